@@ -0,0 +1,42 @@
+use std::fmt::Write;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use extended_float::types::ExtendedFloat;
+use extended_float::types::extended_float::FORMAT_BUFFER_LEN;
+
+fn bench_format(c: &mut Criterion) {
+    for i in [1, 5, 10, 15, 20] {
+        let value = ExtendedFloat::from(4.0 + 3.0 / 10.0_f64.powf(i as f64));
+
+        c.bench_function(format!("bench format {}_decimals", i).as_str(), |b| {
+            b.iter(|| std::hint::black_box(value).format())
+        });
+
+        c.bench_function(format!("bench formatted {}_decimals", i).as_str(), |b| {
+            b.iter(|| std::hint::black_box(value).formatted())
+        });
+
+        let mut output = String::with_capacity(FORMAT_BUFFER_LEN);
+        c.bench_function(format!("bench format_to {}_decimals", i).as_str(), |b| {
+            b.iter(|| {
+                output.clear();
+                std::hint::black_box(value).format_to(&mut output).unwrap();
+            })
+        });
+
+        let mut buffer = [0u8; FORMAT_BUFFER_LEN];
+        c.bench_function(format!("bench format_into {}_decimals", i).as_str(), |b| {
+            b.iter(|| std::hint::black_box(value).format_into(&mut buffer).len())
+        });
+
+        c.bench_function(format!("bench display {}_decimals", i).as_str(), |b| {
+            b.iter(|| {
+                output.clear();
+                write!(output, "{:>12}", std::hint::black_box(value)).unwrap();
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench_format);
+criterion_main!(benches);
@@ -46,7 +46,7 @@ fn main() {
     // Chained operations
     println!("\nChained Operations:");
     println!("({} + {}) * {} = {}", a, b, b, (a + b) * b);
-    println!("{} - {} / {} = {}", a, b, b, a - b / b);
+    println!("{} - {} / {} = {}", a, b, a, a - b / a);
 
     // Special value handling
     println!("\nSpecial Values:");
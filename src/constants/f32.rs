@@ -0,0 +1,24 @@
+/// Epsilon value for f32, used for floating point comparisons and zero detection.
+/// Represents the smallest meaningful difference between two f32 values.
+pub const EPSILON: f32 = 1.0e-6;
+
+/// Decimal precision threshold for f32.
+/// Numbers with absolute value greater than this are formatted as-is
+/// without additional precision handling.
+pub const DECIMAL_PRECISION: f32 = 1e6;
+
+/// Number of significant decimal digits that can be accurately represented in f32.
+/// This value is derived from f32::EPSILON (1.1920929e-7).
+/// Though f32 can technically represent 6-9 digits, 6 is used as a conservative
+/// value to ensure consistent and accurate results.
+pub const DECIMAL_PRECISION_DIGITS: u16 = 6;
+
+/// Minimum exponent for f32 in IEEE 754 representation.
+pub const MIN_EXPONENT: i16 = -127;
+
+/// Maximum exponent for f32 in IEEE 754 representation.
+pub const MAX_EXPONENT: i16 = 128;
+
+/// Total number of possible exponent values for f32.
+/// Used for sizing lookup tables that map from exponent to other values.
+pub const EXPONENT_RANGE: usize = (MAX_EXPONENT - MIN_EXPONENT + 1) as usize;
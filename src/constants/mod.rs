@@ -1 +1,2 @@
+pub mod f32;
 pub mod f64;
@@ -0,0 +1,64 @@
+use crate::constants::f32::{DECIMAL_PRECISION, DECIMAL_PRECISION_DIGITS, EPSILON, MIN_EXPONENT};
+use crate::tables::lookup::f32::{EXTRA_DIGITS_TABLE, PRECISION_TABLE};
+use crate::tables::lookup::{lookup_extra_digits, lookup_precision};
+use crate::traits::Float;
+
+impl Float for f32 {
+    fn epsilon() -> f32 {
+        EPSILON
+    }
+
+    fn exponent(&self) -> i16 {
+        // IEEE 754 single-precision format:
+        // - 1 bit sign
+        // - 8 bits exponent (with bias of 127)
+        // - 23 bits fraction (mantissa)
+        let bits = self.to_bits();
+        let exponent_bits = (bits >> 23) & 0xFF; // Extract the 8 exponent bits
+        let bias = 127; // Exponent bias for f32
+        (exponent_bits as i32 - bias) as i16 // Remove bias to get the actual exponent
+    }
+
+    fn ulp_index(&self) -> i64 {
+        // Positive floats are ordered like their bits, negative floats are mirrored
+        // around zero by negating the magnitude bits
+        let bits = self.to_bits() as i32;
+        let magnitude = bits & i32::MAX;
+        i64::from(if bits < 0 { -magnitude } else { magnitude })
+    }
+
+    fn from_ulp_index(index: i64) -> f32 {
+        // Positions of f32 values always fit, see `ulp_index`
+        let index = index as i32;
+        match index < 0 {
+            true => f32::from_bits(index.unsigned_abs() | 1 << (u32::BITS - 1)),
+            false => f32::from_bits(index as u32),
+        }
+    }
+
+    fn decimal_precision() -> f32 {
+        DECIMAL_PRECISION
+    }
+
+    fn decimal_precision_digits() -> u16 {
+        DECIMAL_PRECISION_DIGITS
+    }
+
+    fn extra_digits(&self) -> u16 {
+        // Use lookup table for fast conversion from binary exponent to extra decimal digits
+        // This avoids runtime log10(2) multiplication and ceiling operations
+        lookup_extra_digits(&EXTRA_DIGITS_TABLE, MIN_EXPONENT, self.exponent())
+    }
+
+    fn precision(&self) -> usize {
+        // Use the lookup table for fast precision retrieval
+        // This approach is significantly faster than calculating at runtime
+        // and provides consistent precision handling across all operations
+        lookup_precision(
+            &PRECISION_TABLE,
+            MIN_EXPONENT,
+            DECIMAL_PRECISION_DIGITS,
+            self.exponent(),
+        )
+    }
+}
@@ -1,7 +1,6 @@
-use crate::constants::f64::{
-    DECIMAL_PRECISION, DECIMAL_PRECISION_DIGITS, EPSILON, MAX_EXPONENT, MIN_EXPONENT,
-};
-use crate::tables::lookup::{EXTRA_DIGITS_TABLE, PRECISION_TABLE};
+use crate::constants::f64::{DECIMAL_PRECISION, DECIMAL_PRECISION_DIGITS, EPSILON, MIN_EXPONENT};
+use crate::tables::lookup::f64::{EXTRA_DIGITS_TABLE, PRECISION_TABLE};
+use crate::tables::lookup::{lookup_extra_digits, lookup_precision};
 use crate::traits::Float;
 
 impl Float for f64 {
@@ -20,6 +19,21 @@ impl Float for f64 {
         (exponent_bits as i32 - bias) as i16 // Remove bias to get the actual exponent
     }
 
+    fn ulp_index(&self) -> i64 {
+        // Positive floats are ordered like their bits, negative floats are mirrored
+        // around zero by negating the magnitude bits
+        let bits = self.to_bits() as i64;
+        let magnitude = bits & i64::MAX;
+        if bits < 0 { -magnitude } else { magnitude }
+    }
+
+    fn from_ulp_index(index: i64) -> f64 {
+        match index < 0 {
+            true => f64::from_bits(index.unsigned_abs() | 1 << (u64::BITS - 1)),
+            false => f64::from_bits(index as u64),
+        }
+    }
+
     fn decimal_precision() -> f64 {
         DECIMAL_PRECISION
     }
@@ -29,37 +43,20 @@ impl Float for f64 {
     }
 
     fn extra_digits(&self) -> u16 {
-        let exponent = self.exponent();
-
-        // Handle special cases or out-of-range exponents
-        if !(MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
-            return 0; // Fallback for any out-of-range values (NaN, Infinity, subnormals)
-        }
-
         // Use lookup table for fast conversion from binary exponent to extra decimal digits
         // This avoids runtime log10(2) multiplication and ceiling operations
-        let index = (exponent - MIN_EXPONENT) as usize;
-        EXTRA_DIGITS_TABLE[index]
+        lookup_extra_digits(&EXTRA_DIGITS_TABLE, MIN_EXPONENT, self.exponent())
     }
 
     fn precision(&self) -> usize {
-        let exponent = self.exponent();
-
-        // Handle special cases or check if exponent is within our lookup table range
-        if !(MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
-            // For out-of-range values, calculate precision dynamically
-            let extra_digits = self.extra_digits();
-            if extra_digits > Self::decimal_precision_digits() {
-                return 0; // No precision left if extra_digits exceeds our total precision
-            } else {
-                return (Self::decimal_precision_digits() - extra_digits) as usize;
-            }
-        }
-
         // Use the lookup table for fast precision retrieval
         // This approach is significantly faster than calculating at runtime
         // and provides consistent precision handling across all operations
-        let index = (exponent - MIN_EXPONENT) as usize;
-        PRECISION_TABLE[index]
+        lookup_precision(
+            &PRECISION_TABLE,
+            MIN_EXPONENT,
+            DECIMAL_PRECISION_DIGITS,
+            self.exponent(),
+        )
     }
 }
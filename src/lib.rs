@@ -1,4 +1,4 @@
-#![feature(likely_unlikely, cold_path)]
+#![feature(likely_unlikely)]
 
 pub mod constants;
 pub mod impls;
@@ -7,4 +7,7 @@ pub mod traits;
 pub mod types;
 pub mod utils;
 
+#[cfg(test)]
+mod test_utils;
+
 pub use traits::{DisplayableFloat, Float};
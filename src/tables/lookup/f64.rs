@@ -0,0 +1,19 @@
+use super::{generate_extra_digits_table, generate_precision_table};
+use crate::constants::f64::{DECIMAL_PRECISION_DIGITS, EXPONENT_RANGE, MIN_EXPONENT};
+
+/// Pre-computed lookup table for extra digits needed when representing binary floating-point values in decimal.
+///
+/// For each possible f64 exponent (-1023 to 1024), this table stores the number of decimal
+/// digits that would be consumed by the binary-to-decimal conversion overhead.
+///
+/// Formula: ceiling(exponent * log10(2)) where log10(2) ≈ 0.301029995663981
+pub const EXTRA_DIGITS_TABLE: [u16; EXPONENT_RANGE] = generate_extra_digits_table(MIN_EXPONENT);
+
+/// Pre-computed lookup table for effective decimal precision available for each exponent.
+///
+/// For each possible f64 exponent, this stores the effective precision (in decimal digits)
+/// available for meaningful representation after accounting for binary-to-decimal conversion.
+///
+/// Formula: max(0, DECIMAL_PRECISION_DIGITS - extra_digits)
+pub const PRECISION_TABLE: [usize; EXPONENT_RANGE] =
+    generate_precision_table(MIN_EXPONENT, DECIMAL_PRECISION_DIGITS);
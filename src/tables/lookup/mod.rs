@@ -0,0 +1,204 @@
+pub mod f32;
+pub mod f64;
+
+/// Generates a lookup table of extra decimal digits needed for each binary exponent.
+///
+/// The binary-to-decimal conversion requires additional decimal digits to represent
+/// the same precision. This function pre-computes these values at compile time for
+/// a float type whose exponents start at `min_exponent` and span `N` values.
+///
+/// Technical details:
+/// - Uses fixed-point arithmetic for compile-time calculation (const fn restrictions)
+/// - Computes ceiling(exponent * log10(2)) for each possible exponent
+/// - For negative exponents resulting in negative values, returns 0 (they don't require extra digits)
+pub const fn generate_extra_digits_table<const N: usize>(min_exponent: i16) -> [u16; N] {
+    // Convert LOG10_2 to fixed point for compile-time calculations
+    // 0.301029995663981 * 1_000_000 = 301030
+    const LOG10_2_FIXED: i32 = 301030;
+    const FIXED_POINT_SCALE: i32 = 1_000_000;
+
+    let mut table = [0u16; N];
+    let mut i = 0;
+
+    while i < N {
+        // Convert array index to actual exponent
+        let exp = (i as i32) + (min_exponent as i32);
+
+        // Fixed-point multiplication of exponent * LOG10_2
+        let mut value = exp * LOG10_2_FIXED;
+
+        // Apply ceiling function: if there's a fractional part, round up
+        if value > 0 && value % FIXED_POINT_SCALE != 0 {
+            value = (value / FIXED_POINT_SCALE) + 1;
+        } else {
+            value /= FIXED_POINT_SCALE;
+        }
+
+        // For negative exponents that would result in negative values, set to 0
+        // (numbers with negative exponents don't need extra digits)
+        if value < 0 {
+            table[i] = 0;
+        } else {
+            table[i] = value as u16;
+        }
+
+        i += 1;
+    }
+
+    table
+}
+
+/// Generates a lookup table of effective precision values for each binary exponent.
+///
+/// For each exponent, this pre-computes the maximum number of meaningful decimal digits
+/// that can be used after accounting for binary-to-decimal conversion overhead, given
+/// the `decimal_precision_digits` of the float type.
+///
+/// This improves performance by avoiding these calculations at runtime and provides
+/// consistent precision handling across all floating-point operations.
+pub const fn generate_precision_table<const N: usize>(
+    min_exponent: i16,
+    decimal_precision_digits: u16,
+) -> [usize; N] {
+    let extra_digits = generate_extra_digits_table::<N>(min_exponent);
+    let mut table = [0usize; N];
+    let mut i = 0;
+
+    while i < N {
+        // If extra_digits exceeds our precision, result is 0
+        if extra_digits[i] > decimal_precision_digits {
+            table[i] = 0;
+        } else {
+            // Store directly as usize to avoid runtime conversions
+            table[i] = (decimal_precision_digits - extra_digits[i]) as usize;
+        }
+
+        i += 1;
+    }
+
+    table
+}
+
+/// Looks up the extra decimal digits for a binary exponent in a table produced by
+/// [`generate_extra_digits_table`].
+///
+/// Exponents outside of the table (NaN, Infinity, subnormals of exotic types) need no
+/// extra digits and fall back to 0.
+#[inline(always)]
+pub fn lookup_extra_digits<const N: usize>(
+    table: &[u16; N],
+    min_exponent: i16,
+    exponent: i16,
+) -> u16 {
+    match table_index::<N>(min_exponent, exponent) {
+        Some(index) => table[index],
+        None => 0,
+    }
+}
+
+/// Looks up the effective decimal precision for a binary exponent in a table produced by
+/// [`generate_precision_table`].
+///
+/// Exponents outside of the table don't consume any digits, so the full
+/// `decimal_precision_digits` are available for them.
+#[inline(always)]
+pub fn lookup_precision<const N: usize>(
+    table: &[usize; N],
+    min_exponent: i16,
+    decimal_precision_digits: u16,
+    exponent: i16,
+) -> usize {
+    match table_index::<N>(min_exponent, exponent) {
+        Some(index) => table[index],
+        None => decimal_precision_digits as usize,
+    }
+}
+
+#[inline(always)]
+fn table_index<const N: usize>(min_exponent: i16, exponent: i16) -> Option<usize> {
+    let index = exponent as i32 - min_exponent as i32;
+    if index >= 0 && (index as usize) < N {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::constants;
+
+    #[test]
+    fn test_extra_digits_table() {
+        let table = generate_extra_digits_table::<8>(-3);
+
+        // Negative and zero exponents don't need extra digits
+        assert_eq!(&table[..4], &[0, 0, 0, 0]);
+        // ceil(1 * log10(2)) = 1, ceil(2 * log10(2)) = 1, ceil(3 * log10(2)) = 1,
+        // ceil(4 * log10(2)) = 2
+        assert_eq!(&table[4..], &[1, 1, 1, 2]);
+
+        let table = generate_extra_digits_table::<1>(10);
+        assert_eq!(table, [4]);
+    }
+
+    #[test]
+    fn test_precision_table() {
+        let table = generate_precision_table::<5>(48, 15);
+        // ceil(e * log10(2)) for e in 48..=52 is 15, 15, 16, 16, 16
+        assert_eq!(table, [0, 0, 0, 0, 0]);
+
+        let table = generate_precision_table::<4>(-1, 15);
+        assert_eq!(table, [15, 15, 14, 14]);
+
+        let table = generate_precision_table::<4>(-1, 6);
+        assert_eq!(table, [6, 6, 5, 5]);
+    }
+
+    #[test]
+    fn test_lookup() {
+        let extra_digits = generate_extra_digits_table::<8>(-3);
+        let precision = generate_precision_table::<8>(-3, 6);
+
+        assert_eq!(lookup_extra_digits(&extra_digits, -3, -3), 0);
+        assert_eq!(lookup_extra_digits(&extra_digits, -3, 4), 2);
+        assert_eq!(lookup_precision(&precision, -3, 6, 4), 4);
+
+        // Out-of-range exponents fall back to no extra digits and full precision
+        assert_eq!(lookup_extra_digits(&extra_digits, -3, -4), 0);
+        assert_eq!(lookup_extra_digits(&extra_digits, -3, 5), 0);
+        assert_eq!(lookup_precision(&precision, -3, 6, -4), 6);
+        assert_eq!(lookup_precision(&precision, -3, 6, i16::MAX), 6);
+    }
+
+    #[test]
+    fn test_type_tables() {
+        assert_eq!(
+            f64::EXTRA_DIGITS_TABLE.len(),
+            constants::f64::EXPONENT_RANGE
+        );
+        assert_eq!(
+            f32::EXTRA_DIGITS_TABLE.len(),
+            constants::f32::EXPONENT_RANGE
+        );
+
+        // Both types share the generator, so overlapping exponents must agree
+        for exponent in constants::f32::MIN_EXPONENT..=constants::f32::MAX_EXPONENT {
+            let index_f64 = (exponent - constants::f64::MIN_EXPONENT) as usize;
+            let index_f32 = (exponent - constants::f32::MIN_EXPONENT) as usize;
+            assert_eq!(
+                f64::EXTRA_DIGITS_TABLE[index_f64],
+                f32::EXTRA_DIGITS_TABLE[index_f32]
+            );
+        }
+
+        // 1.0 has exponent 0, so the full precision is available
+        let index_f64 = (0 - constants::f64::MIN_EXPONENT) as usize;
+        let index_f32 = (0 - constants::f32::MIN_EXPONENT) as usize;
+        assert_eq!(f64::PRECISION_TABLE[index_f64], 15);
+        assert_eq!(f32::PRECISION_TABLE[index_f32], 6);
+    }
+}
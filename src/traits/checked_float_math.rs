@@ -0,0 +1,90 @@
+use crate::types::extended_float::ConversionError;
+
+/// Trait for elementary math functions that check for invalid results (NaN, infinity)
+///
+/// This trait provides checked versions of the elementary functions that return
+/// `Option<Self>` instead of panicking when the result would be NaN or infinite,
+/// for example the square root of a negative number or the logarithm of zero.
+///
+/// # Examples
+///
+/// ```
+/// use extended_float::types::ExtendedFloat;
+/// use extended_float::types::extended_float::ConversionError;
+/// use extended_float::traits::CheckedFloatMath;
+///
+/// assert_eq!(ExtendedFloat::new(4.0).checked_sqrt(), Some(ExtendedFloat::new(2.0)));
+/// assert!(ExtendedFloat::new(-1.0).checked_sqrt().is_none());
+/// assert_eq!(ExtendedFloat::new(0.0).try_ln(), Err(ConversionError::Infinite));
+/// ```
+pub trait CheckedFloatMath<Rhs = Self> {
+    /// Computes the square root, returning None for negative values
+    fn checked_sqrt(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Raises to an integer power, returning None if the result would be NaN or infinite
+    fn checked_powi(&self, n: i32) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Raises to a power, returning None if the result would be NaN or infinite
+    fn checked_powf(&self, n: Rhs) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Computes `e^self`, returning None if the result would be infinite
+    fn checked_exp(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Computes the natural logarithm, returning None for zero and negative values
+    fn checked_ln(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Computes the base 10 logarithm, returning None for zero and negative values
+    fn checked_log10(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Computes the base 2 logarithm, returning None for zero and negative values
+    fn checked_log2(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Similar to checked_sqrt but returns a Result with detailed error information
+    fn try_sqrt(&self) -> Result<Self, ConversionError>
+    where
+        Self: Sized;
+
+    /// Similar to checked_powi but returns a Result with detailed error information
+    fn try_powi(&self, n: i32) -> Result<Self, ConversionError>
+    where
+        Self: Sized;
+
+    /// Similar to checked_powf but returns a Result with detailed error information
+    fn try_powf(&self, n: Rhs) -> Result<Self, ConversionError>
+    where
+        Self: Sized;
+
+    /// Similar to checked_exp but returns a Result with detailed error information
+    fn try_exp(&self) -> Result<Self, ConversionError>
+    where
+        Self: Sized;
+
+    /// Similar to checked_ln but returns a Result with detailed error information
+    fn try_ln(&self) -> Result<Self, ConversionError>
+    where
+        Self: Sized;
+
+    /// Similar to checked_log10 but returns a Result with detailed error information
+    fn try_log10(&self) -> Result<Self, ConversionError>
+    where
+        Self: Sized;
+
+    /// Similar to checked_log2 but returns a Result with detailed error information
+    fn try_log2(&self) -> Result<Self, ConversionError>
+    where
+        Self: Sized;
+}
@@ -3,7 +3,7 @@ use crate::types::extended_float::ConversionError;
 /// Trait for safe arithmetic operations that check for invalid results (NaN, infinity)
 ///
 /// This trait provides checked versions of common arithmetic operations that
-/// return Option<Self> instead of panicking when the result would be NaN or infinite.
+/// return `Option<Self>` instead of panicking when the result would be NaN or infinite.
 ///
 /// # Examples
 ///
@@ -45,6 +45,19 @@ pub trait CheckedFloatOps<Rhs = Self> {
     where
         Self: Sized;
 
+    /// Performs a checked fused multiply-add `self * a + b` with a single rounding,
+    /// returning None if the result would be NaN or infinite
+    ///
+    /// The default implementation is `checked_mul` followed by `checked_add`, which
+    /// rounds twice and also fails if the intermediate product overflows. Implementors
+    /// should override it with a fused operation.
+    fn checked_mul_add(&self, a: Rhs, b: Rhs) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.checked_mul(a)?.checked_add(b)
+    }
+
     /// Similar to checked_add but returns a Result with detailed error information
     fn try_add(&self, rhs: Rhs) -> Result<Self, ConversionError>
     where
@@ -69,4 +82,15 @@ pub trait CheckedFloatOps<Rhs = Self> {
     fn try_rem(&self, rhs: Rhs) -> Result<Self, ConversionError>
     where
         Self: Sized;
+
+    /// Similar to checked_mul_add but returns a Result with detailed error information
+    ///
+    /// The default implementation is `try_mul` followed by `try_add`, see
+    /// [`checked_mul_add`](Self::checked_mul_add).
+    fn try_mul_add(&self, a: Rhs, b: Rhs) -> Result<Self, ConversionError>
+    where
+        Self: Sized,
+    {
+        self.try_mul(a)?.try_add(b)
+    }
 }
@@ -2,6 +2,10 @@ use std::fmt;
 
 use super::float::Float;
 
-pub trait DisplayableFloat: Float + fmt::Debug + fmt::Display {}
+/// Float type that can be wrapped and formatted.
+///
+/// Float types are plain values, the `'static` bound lets the formatting pick the
+/// dedicated `f32` and `f64` paths by type.
+pub trait DisplayableFloat: Float + fmt::Debug + fmt::Display + fmt::LowerExp + 'static {}
 
-impl<T: Float + fmt::Debug + fmt::Display> DisplayableFloat for T {}
+impl<T: Float + fmt::Debug + fmt::Display + fmt::LowerExp + 'static> DisplayableFloat for T {}
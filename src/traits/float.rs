@@ -23,6 +23,30 @@ pub trait Float: NumFloat + FloatConst {
     /// adjusts for the bias to get the actual power of 2.
     fn exponent(&self) -> i16;
 
+    /// Returns the position of the number among all floats of this type.
+    ///
+    /// Adjacent floats have adjacent positions and both zeros are at position zero,
+    /// so the difference of two positions is their distance in units in the last
+    /// place (ULPs).
+    ///
+    /// The default implementation derives the position from
+    /// [`integer_decode`](NumFloat::integer_decode), implementors with direct access
+    /// to the bits should override it.
+    fn ulp_index(&self) -> i64 {
+        decoded_ulp_index(*self)
+    }
+
+    /// Returns the float at the given position, the inverse of [`ulp_index`](Self::ulp_index).
+    ///
+    /// Position zero is positive zero. The position must be within the range of this
+    /// type, one past the largest finite value gives infinity.
+    ///
+    /// The default implementation scales the mantissa by powers of two, implementors
+    /// with direct access to the bits should override it.
+    fn from_ulp_index(index: i64) -> Self {
+        encoded_ulp_index(index)
+    }
+
     /// Returns the decimal precision threshold for this floating point type.
     ///
     /// This represents the magnitude above which precision handling becomes
@@ -49,3 +73,105 @@ pub trait Float: NumFloat + FloatConst {
     /// Formula: max(0, decimal_precision_digits - extra_digits)
     fn precision(&self) -> usize;
 }
+
+/// Position of `value` among all floats of its type, computed from its decoded parts
+///
+/// Each binade holds as many floats as the smallest normal mantissa, and subnormals
+/// share the binade of the smallest normal exponent.
+fn decoded_ulp_index<T: NumFloat>(value: T) -> i64 {
+    let (mantissa, exponent, sign) = value.integer_decode();
+    let (binade_size, _, _) = T::one().integer_decode();
+    let (_, min_exponent, _) = T::min_positive_value().integer_decode();
+    let magnitude = match exponent >= min_exponent {
+        true => {
+            (i64::from(exponent - min_exponent) + 1) * binade_size as i64
+                + (mantissa - binade_size) as i64
+        }
+        // Subnormals decode with a smaller exponent and a mantissa scaled to match
+        false => {
+            mantissa
+                .checked_shr((min_exponent - exponent) as u32)
+                .unwrap_or(0) as i64
+        }
+    };
+    match sign < 0 {
+        true => -magnitude,
+        false => magnitude,
+    }
+}
+
+/// Float at the given position, the inverse of [`decoded_ulp_index`]
+fn encoded_ulp_index<T: NumFloat>(index: i64) -> T {
+    let (binade_size, _, _) = T::one().integer_decode();
+    let (_, min_exponent, _) = T::min_positive_value().integer_decode();
+    let magnitude = index.unsigned_abs();
+    let binade = magnitude / binade_size;
+    // Mantissa in [1, 2) for normals and in [0, 1) for subnormals, both exact
+    let mantissa = match binade {
+        0 => magnitude,
+        _ => binade_size + magnitude % binade_size,
+    };
+    let mantissa = cast::<T>(mantissa) / cast::<T>(binade_size);
+    // Powers of two from the smallest normal upward are exact, past the largest
+    // finite binade this overflows to infinity
+    let two = T::one() + T::one();
+    let exponent = min_exponent + binade.max(1) as i16 - 1 + binade_size.ilog2() as i16;
+    let value = mantissa * two.powi(i32::from(exponent));
+    match index < 0 {
+        true => -value,
+        false => value,
+    }
+}
+
+fn cast<T: NumFloat>(value: u64) -> T {
+    <T as num_traits::NumCast>::from(value).expect("Mantissa fits into the float type")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_default_ulp_index() {
+        for value in [
+            0.0,
+            -0.0,
+            f64::from_bits(1),
+            1e-310,
+            f64::MIN_POSITIVE - f64::from_bits(1),
+            f64::MIN_POSITIVE,
+            0.1,
+            1.0,
+            1.5,
+            4e15,
+            1e300,
+            f64::MAX,
+            f64::INFINITY,
+        ] {
+            for value in [value, -value] {
+                let index = value.ulp_index();
+                assert_eq!(decoded_ulp_index(value), index, "{:e}", value);
+                assert_eq!(encoded_ulp_index::<f64>(index), value, "{:e}", value);
+            }
+        }
+
+        for value in [
+            f32::from_bits(1),
+            1e-40,
+            f32::MIN_POSITIVE,
+            0.1,
+            1.0,
+            3e7,
+            f32::MAX,
+            f32::INFINITY,
+        ] {
+            for value in [value, -value] {
+                let index = value.ulp_index();
+                assert_eq!(decoded_ulp_index(value), index, "{:e}", value);
+                assert_eq!(encoded_ulp_index::<f32>(index), value, "{:e}", value);
+            }
+        }
+    }
+}
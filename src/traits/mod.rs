@@ -6,3 +6,9 @@ pub use displayable_float::DisplayableFloat;
 
 mod checked_float_ops;
 pub use checked_float_ops::CheckedFloatOps;
+
+mod checked_float_math;
+pub use checked_float_math::CheckedFloatMath;
+
+mod tolerance;
+pub use tolerance::Tolerance;
@@ -0,0 +1,40 @@
+use super::DisplayableFloat;
+
+/// Policy deciding when two distinct values compare equal
+///
+/// The policy is a type parameter of [`TolerantFloat`](crate::types::extended_float::TolerantFloat),
+/// so each domain type picks its comparison semantics at compile time. `PartialEq`,
+/// `Ord` and `PartialOrd` call [`approx_eq`](Self::approx_eq) only for values that
+/// differ and are neither NaN nor infinite.
+///
+/// Built-in policies are [`DefaultTolerance`](crate::types::extended_float::DefaultTolerance),
+/// [`Absolute`](crate::types::extended_float::Absolute), [`Relative`](crate::types::extended_float::Relative),
+/// [`Combined`](crate::types::extended_float::Combined) and [`Ulps`](crate::types::extended_float::Ulps).
+///
+/// # Examples
+///
+/// ```
+/// use extended_float::traits::Tolerance;
+/// use extended_float::types::extended_float::TolerantFloat;
+///
+/// /// Prices are equal when they round to the same cent
+/// struct Cents;
+///
+/// impl Tolerance<f64> for Cents {
+///     fn approx_eq(a: f64, b: f64) -> bool {
+///         (a * 100.0).round() == (b * 100.0).round()
+///     }
+/// }
+///
+/// type Price = TolerantFloat<f64, Cents>;
+///
+/// assert_eq!(Price::new(10.001), Price::new(10.004));
+/// assert_ne!(Price::new(10.001), Price::new(10.011));
+/// ```
+pub trait Tolerance<T: DisplayableFloat> {
+    /// Name that `Debug` shows for values with this policy
+    const NAME: &'static str = "TolerantFloat";
+
+    /// Returns true if the two finite values should compare equal
+    fn approx_eq(a: T, b: T) -> bool;
+}
@@ -0,0 +1,310 @@
+use std::ops::{AddAssign, SubAssign};
+
+use super::{ExtendedFloat, TolerantFloat};
+use crate::traits::{DisplayableFloat, Tolerance};
+
+/// Running total that keeps the exact sum of everything added to it.
+///
+/// The total is stored as a few non-overlapping partial sums (Shewchuk's algorithm),
+/// the rounding error of every update goes into the lower partials instead of being
+/// lost. [`value`](Self::value) rounds the exact total once, so the result doesn't
+/// depend on the order of the updates or on how they were split between accumulators
+/// that are [`merge`](Self::merge)d later.
+///
+/// # Examples
+///
+/// ```
+/// use extended_float::types::ExtendedFloat;
+/// use extended_float::types::extended_float::Accumulator;
+///
+/// let mut first = Accumulator::new();
+/// let mut second = Accumulator::new();
+/// for _ in 0..500 {
+///     first += ExtendedFloat::new(0.01);
+///     second += 0.01;
+/// }
+/// second -= ExtendedFloat::new(5.0);
+///
+/// first.merge(&second);
+/// assert_eq!(first.value().format(), "5");
+/// assert_eq!(first.value().downgrade(), 5.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Accumulator<T: DisplayableFloat> {
+    /// Non-overlapping partial sums in increasing order of magnitude
+    partials: Vec<T>,
+}
+
+impl<T: DisplayableFloat> Accumulator<T> {
+    /// Creates an accumulator with a total of zero
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            partials: Vec::new(),
+        }
+    }
+
+    /// Returns the total rounded to the nearest float.
+    ///
+    /// The partial sums don't depend on a comparison policy, so values of any
+    /// tolerance can be added and the total comes with the default one. Convert it with
+    /// [`with_tolerance`](TolerantFloat::with_tolerance) where another policy is needed.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the rounded total is infinite, which can only happen
+    /// right at the edge of the float range.
+    pub fn value(&self) -> ExtendedFloat<T> {
+        let mut partials = self.partials.iter().rev().copied();
+        let Some(mut high) = partials.next() else {
+            return ExtendedFloat::new(T::zero());
+        };
+
+        // Sums from the top until the result is inexact, the rest can only matter
+        // for breaking a tie
+        let mut low = T::zero();
+        for partial in partials.by_ref() {
+            let sum = high + partial;
+            low = partial - (sum - high);
+            high = sum;
+            if !low.is_zero() {
+                break;
+            }
+        }
+
+        // The rounding went half-way and the remaining partials push it further,
+        // so the exact total rounds away from `high`
+        if let Some(next) = partials.next() {
+            let same_sign =
+                (low < T::zero() && next < T::zero()) || (low > T::zero() && next > T::zero());
+            if same_sign {
+                let twice_low = low + low;
+                let rounded = high + twice_low;
+                if rounded - high == twice_low {
+                    high = rounded;
+                }
+            }
+        }
+
+        ExtendedFloat::new(high)
+    }
+
+    /// Adds the total of another accumulator, as if all of its updates were made
+    /// to this one
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a partial sum would be infinite.
+    pub fn merge(&mut self, other: &Self) {
+        for &partial in &other.partials {
+            self.add_exact(partial);
+        }
+    }
+
+    /// Adds a finite value to the partials, keeping the sum exact
+    fn add_exact(&mut self, value: T) {
+        let mut value = value;
+        let mut kept = 0;
+        for index in 0..self.partials.len() {
+            let mut partial = self.partials[index];
+            if value.abs() < partial.abs() {
+                std::mem::swap(&mut value, &mut partial);
+            }
+
+            let high = ExtendedFloat::new(value + partial).downgrade();
+            let low = partial - (high - value);
+            if !low.is_zero() {
+                self.partials[kept] = low;
+                kept += 1;
+            }
+            value = high;
+        }
+
+        self.partials.truncate(kept);
+        self.partials.push(value);
+    }
+}
+
+impl<T: DisplayableFloat> Default for Accumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> AddAssign<TolerantFloat<T, Tol>> for Accumulator<T> {
+    /// Adds a value to the total.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if a partial sum would be infinite.
+    fn add_assign(&mut self, value: TolerantFloat<T, Tol>) {
+        self.add_exact(value.downgrade());
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> SubAssign<TolerantFloat<T, Tol>> for Accumulator<T> {
+    /// Subtracts a value from the total.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if a partial sum would be infinite.
+    fn sub_assign(&mut self, value: TolerantFloat<T, Tol>) {
+        self.add_exact(-value.downgrade());
+    }
+}
+
+impl<T: DisplayableFloat> AddAssign<T> for Accumulator<T> {
+    /// Adds a raw value to the total.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - The value is invalid (NaN, infinity)
+    /// - A partial sum would be infinite
+    fn add_assign(&mut self, value: T) {
+        *self += ExtendedFloat::new(value);
+    }
+}
+
+impl<T: DisplayableFloat> SubAssign<T> for Accumulator<T> {
+    /// Subtracts a raw value from the total.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - The value is invalid (NaN, infinity)
+    /// - A partial sum would be infinite
+    fn sub_assign(&mut self, value: T) {
+        *self -= ExtendedFloat::new(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::test_utils::generator;
+    use crate::types::extended_float::Ulps;
+
+    #[test]
+    fn test_accumulator_basics() {
+        let accumulator = Accumulator::<f64>::new();
+        assert_eq!(accumulator.value().downgrade(), 0.0);
+
+        let mut accumulator = Accumulator::new();
+        for _ in 0..1_000_000 {
+            accumulator += 0.01;
+        }
+        assert_eq!(accumulator.value().downgrade(), 10_000.0);
+
+        let mut accumulator = Accumulator::default();
+        accumulator += 1e100;
+        accumulator += 1.0;
+        accumulator -= 1e100;
+        assert_eq!(accumulator.value().downgrade(), 1.0);
+
+        let mut accumulator = Accumulator::new();
+        accumulator += ExtendedFloat::new(0.1);
+        accumulator += ExtendedFloat::new(0.2);
+        accumulator -= ExtendedFloat::new(0.3);
+        // The exact total of the binary values, which is within epsilon of zero
+        assert_eq!(accumulator.value().downgrade(), 2.7755575615628914e-17);
+        assert_eq!(accumulator.value(), ExtendedFloat::new(0.0));
+        assert_eq!(accumulator.value().format(), "0");
+    }
+
+    #[test]
+    fn test_accumulator_tolerance() {
+        type Exact = TolerantFloat<f64, Ulps<0>>;
+
+        let mut accumulator = Accumulator::new();
+        accumulator += Exact::new(0.1);
+        accumulator += ExtendedFloat::new(0.2);
+        accumulator -= Exact::new(0.3);
+        let total = accumulator.value().with_tolerance::<Ulps<0>>();
+        assert_ne!(total, Exact::new(0.0));
+        assert_eq!(total.downgrade(), 2.7755575615628914e-17);
+    }
+
+    #[test]
+    fn test_accumulator_f32() {
+        let mut accumulator = Accumulator::new();
+        for _ in 0..100_000 {
+            accumulator += 0.3_f32;
+        }
+        assert_eq!(accumulator.value().format(), "30000");
+    }
+
+    #[test]
+    fn test_accumulator_rounds_ties_correctly() {
+        // The exact total is just above half an ulp over 1, a two-step
+        // rounding would give 1
+        let mut accumulator = Accumulator::new();
+        accumulator += 1.0;
+        accumulator += f64::EPSILON / 2.0;
+        accumulator += f64::EPSILON / 2.0 * f64::EPSILON;
+        assert_eq!(accumulator.value().downgrade(), 1.0 + f64::EPSILON);
+
+        let mut accumulator = Accumulator::new();
+        accumulator += 1.0;
+        accumulator += f64::EPSILON / 2.0;
+        assert_eq!(accumulator.value().downgrade(), 1.0);
+    }
+
+    #[test]
+    fn test_accumulator_split_independent() {
+        let mut next = generator(0x2545_F491_4F6C_DD1D);
+
+        for _ in 0..200 {
+            let values: Vec<f64> = (0..1_000)
+                .map(|_| {
+                    let magnitude = 10f64.powi((next() % 12) as i32 - 4);
+                    let value = (next() % 2_000_001) as f64 / 1_000_000.0 - 1.0;
+                    value * magnitude
+                })
+                .collect();
+
+            let mut whole = Accumulator::new();
+            for &value in &values {
+                whole += value;
+            }
+
+            // Split at random points, accumulate each part in reverse and merge
+            // in reverse order
+            let mut parts = Vec::new();
+            let mut rest = values.as_slice();
+            while !rest.is_empty() {
+                let (part, tail) = rest.split_at(((next() % 300) as usize + 1).min(rest.len()));
+                let mut accumulator = Accumulator::new();
+                for &value in part.iter().rev() {
+                    accumulator += value;
+                }
+                parts.push(accumulator);
+                rest = tail;
+            }
+            let mut merged = Accumulator::new();
+            for part in parts.iter().rev() {
+                merged.merge(part);
+            }
+
+            assert_eq!(merged.value().downgrade(), whole.value().downgrade());
+            assert_eq!(merged.value().format(), whole.value().format());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support infinite values")]
+    fn test_accumulator_overflow() {
+        let mut accumulator = Accumulator::new();
+        accumulator += f64::MAX;
+        accumulator += f64::MAX;
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support NaN values")]
+    fn test_accumulator_nan() {
+        let mut accumulator = Accumulator::new();
+        accumulator += f64::NAN;
+    }
+}
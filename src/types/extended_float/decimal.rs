@@ -0,0 +1,596 @@
+use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt::{self, Write};
+
+use super::RoundingMode;
+use super::formatted::SliceWriter;
+use crate::traits::{DisplayableFloat, Float};
+
+/// Maximum number of significant digits held by [`Decimal`].
+///
+/// Shortest round-trip representations need at most 17 digits for f64, exact fixed-point
+/// output rounded at a dynamic precision stays below 32.
+const MAX_DIGITS: usize = 32;
+
+/// Space for the exact fixed-point output parsed by [`Decimal::from_fixed`]
+const FIXED_SCRATCH_LEN: usize = 48;
+
+/// Decimal representation of a finite float: `0.d1d2...dn * 10^point`.
+///
+/// Digits are stored as ASCII without leading or trailing zeros, so every value has a
+/// single representation and zero has no digits at all.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Decimal {
+    negative: bool,
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+    /// Number of digits before the decimal point, can be negative or exceed `len`
+    point: i32,
+}
+
+impl Decimal {
+    pub(crate) const ZERO: Self = Self {
+        negative: false,
+        digits: [0; MAX_DIGITS],
+        len: 0,
+        point: 0,
+    };
+
+    /// Creates a decimal from the shortest representation that round-trips to `value`.
+    ///
+    /// `f32` and `f64` values are written by ryu, other float types fall back to core
+    /// formatting. `value` must be finite.
+    #[inline]
+    pub(crate) fn from_shortest<T: DisplayableFloat>(value: T) -> Self {
+        let mut buffer = ryu::Buffer::new();
+        let any: &dyn Any = &value;
+        if let Some(&value) = any.downcast_ref::<f64>() {
+            return Self::parse(buffer.format_finite(value).as_bytes());
+        }
+        if let Some(&value) = any.downcast_ref::<f32>() {
+            return Self::parse(buffer.format_finite(value).as_bytes());
+        }
+        Self::from_display(value)
+    }
+
+    /// Creates a decimal from the shortest representation chosen by core formatting.
+    ///
+    /// It can differ from [`from_shortest`](Self::from_shortest) in the last digit when two
+    /// candidates are equally close, and is used where output must match `{}` exactly.
+    /// `value` must be finite.
+    pub(crate) fn from_display<T: Float + fmt::LowerExp>(value: T) -> Self {
+        let mut scratch = [0u8; FIXED_SCRATCH_LEN];
+        let mut writer = SliceWriter::new(&mut scratch);
+        write!(writer, "{:e}", value).unwrap();
+        let len = writer.len();
+        Self::parse(&scratch[..len])
+    }
+
+    /// Creates a decimal from the exact value rounded at `precision` fractional digits.
+    ///
+    /// Relies on core formatting, which rounds the exact binary value half to even.
+    /// `value` must be finite and its integer part must fit into [`MAX_DIGITS`] digits.
+    fn from_fixed<T: Float + fmt::Display>(value: T, precision: usize) -> Self {
+        let mut scratch = [0u8; FIXED_SCRATCH_LEN];
+        let mut writer = SliceWriter::new(&mut scratch);
+        write!(writer, "{:.*}", precision, value).unwrap();
+        let len = writer.len();
+        Self::parse(&scratch[..len])
+    }
+
+    /// Creates a decimal from `value` rounded at `precision` fractional digits, with the
+    /// same result as formatting the exact value with `{:.precision$}`.
+    ///
+    /// The shortest representation is rounded directly. It lies within half an ULP of the
+    /// exact value, and rounding at a precision that leaves at least one digit of the
+    /// shortest representation out can only differ when the dropped digits are an exact
+    /// tie, so ties are resolved by exact formatting.
+    #[inline]
+    pub(crate) fn from_float<T: DisplayableFloat>(value: T, precision: usize) -> Self {
+        let mut decimal = Self::from_shortest(value);
+        if decimal.is_tie_at(precision) {
+            return Self::from_fixed(value, precision);
+        }
+        decimal.round_fraction(precision, RoundingMode::HalfEven);
+        decimal
+    }
+
+    /// Parses the output of ryu or core formatting: `[-]digits[.digits][e[-]digits]`
+    fn parse(bytes: &[u8]) -> Self {
+        let mut decimal = Self::ZERO;
+
+        let mut pos = 0;
+        if bytes.first() == Some(&b'-') {
+            decimal.negative = true;
+            pos += 1;
+        }
+
+        let mut seen_point = false;
+        while let Some(&byte) = bytes.get(pos) {
+            match byte {
+                b'0'..=b'9' => {
+                    if decimal.len == 0 && byte == b'0' {
+                        // Leading zeros after the decimal point shift it to the right
+                        if seen_point {
+                            decimal.point -= 1;
+                        }
+                    } else {
+                        debug_assert!(decimal.len < MAX_DIGITS);
+                        decimal.digits[decimal.len] = byte;
+                        decimal.len += 1;
+                        if !seen_point {
+                            decimal.point += 1;
+                        }
+                    }
+                }
+                b'.' => seen_point = true,
+                _ => break,
+            }
+            pos += 1;
+        }
+
+        if bytes.get(pos) == Some(&b'e') {
+            pos += 1;
+            let negative_exponent = bytes.get(pos) == Some(&b'-');
+            if negative_exponent {
+                pos += 1;
+            }
+            let exponent = bytes[pos..]
+                .iter()
+                .fold(0i32, |exponent, &byte| exponent * 10 + (byte - b'0') as i32);
+            decimal.point += if negative_exponent {
+                -exponent
+            } else {
+                exponent
+            };
+        }
+
+        decimal.trim();
+        decimal
+    }
+
+    /// Returns true if the value is below zero, i.e. negative and not zero
+    #[inline]
+    pub(crate) fn is_negative(&self) -> bool {
+        self.negative && !self.is_zero()
+    }
+
+    /// Returns true if the value has no non-zero digits
+    #[inline]
+    pub(crate) fn is_zero(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of fractional digits
+    #[inline]
+    pub(crate) fn fraction_digits(&self) -> usize {
+        (self.len as i64 - self.point as i64).max(0) as usize
+    }
+
+    /// Number of digits kept when rounding at `fraction_digits`, negative if even
+    /// the first digit is beyond that
+    #[inline]
+    fn kept_digits(&self, fraction_digits: usize) -> i64 {
+        self.point as i64 + fraction_digits as i64
+    }
+
+    /// Returns true if the digits dropped by rounding at `fraction_digits` are exactly `5`
+    #[inline]
+    fn is_tie_at(&self, fraction_digits: usize) -> bool {
+        let keep = self.kept_digits(fraction_digits);
+        keep >= 0 && keep as usize + 1 == self.len && self.digits[self.len - 1] == b'5'
+    }
+
+    /// Rounds according to `mode`, keeping at most `fraction_digits` fractional digits
+    #[inline]
+    pub(crate) fn round_fraction(&mut self, fraction_digits: usize, mode: RoundingMode) {
+        self.round_to(self.kept_digits(fraction_digits), mode);
+    }
+
+    /// Rounds according to `mode`, keeping at most `significant_digits` digits
+    #[inline]
+    pub(crate) fn round_significant(&mut self, significant_digits: usize, mode: RoundingMode) {
+        self.round_to(significant_digits as i64, mode);
+    }
+
+    /// Rounds according to `mode`, keeping at most `keep` digits counted from the first one
+    fn round_to(&mut self, keep: i64, mode: RoundingMode) {
+        if keep >= self.len as i64 {
+            return;
+        }
+
+        // Position of the last kept place, as the `point` of a single digit there
+        let unit_point = (self.point as i64 - keep + 1) as i32;
+        let (half, last_kept_odd) = match keep {
+            // Even the first digit is below half a unit of the last kept place
+            ..0 => (Ordering::Less, false),
+            _ => {
+                let keep = keep as usize;
+                let half = match self.digits[keep].cmp(&b'5') {
+                    Ordering::Equal if keep + 1 < self.len => Ordering::Greater,
+                    half => half,
+                };
+                let last_kept_odd = keep > 0 && (self.digits[keep - 1] - b'0') % 2 == 1;
+                (half, last_kept_odd)
+            }
+        };
+
+        self.len = keep.max(0) as usize;
+        if mode.rounds_up(self.negative, half, last_kept_odd) {
+            self.increment(unit_point);
+        }
+        self.trim();
+    }
+
+    /// Adds one unit in the place described by `unit_point`, assuming no digits are
+    /// stored beyond it
+    fn increment(&mut self, unit_point: i32) {
+        while self.len > 0 {
+            let last = &mut self.digits[self.len - 1];
+            if *last == b'9' {
+                self.len -= 1;
+            } else {
+                *last += 1;
+                return;
+            }
+        }
+
+        // All digits were nines (or there were none): the carry becomes the only digit,
+        // one place above the previous first digit
+        self.point = if self.point >= unit_point {
+            self.point + 1
+        } else {
+            unit_point
+        };
+        self.digits[0] = b'1';
+        self.len = 1;
+    }
+
+    /// Removes trailing zeros, normalizing zero to no digits
+    #[inline]
+    fn trim(&mut self) {
+        while self.len > 0 && self.digits[self.len - 1] == b'0' {
+            self.len -= 1;
+        }
+        if self.len == 0 {
+            self.point = 0;
+        }
+    }
+
+    /// Writes the value in fixed-point notation with at least `min_fraction_digits`
+    /// fractional digits, padding with zeros. Zero is written without a sign.
+    pub(crate) fn write_fixed<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        min_fraction_digits: usize,
+    ) -> fmt::Result {
+        if self.is_negative() {
+            w.write_char('-')?;
+        }
+
+        let digits = &self.digits[..self.len];
+        let point = self.point;
+
+        let fraction_digits = if point <= 0 {
+            w.write_char('0')?;
+            if self.len > 0 || min_fraction_digits > 0 {
+                w.write_char('.')?;
+            }
+            write_zeros(w, (-point) as usize)?;
+            write_ascii(w, digits)?;
+            (-point) as usize + self.len
+        } else if point as usize >= self.len {
+            write_ascii(w, digits)?;
+            write_zeros(w, point as usize - self.len)?;
+            if min_fraction_digits > 0 {
+                w.write_char('.')?;
+            }
+            0
+        } else {
+            let point = point as usize;
+            write_ascii(w, &digits[..point])?;
+            w.write_char('.')?;
+            write_ascii(w, &digits[point..])?;
+            self.len - point
+        };
+
+        write_zeros(w, min_fraction_digits.saturating_sub(fraction_digits))
+    }
+
+    /// Writes the value in exponent notation with the exponent a multiple of
+    /// `exponent_step` and at least `min_fraction_digits` fractional mantissa digits,
+    /// like `1.5e3` or, with a step of 3, `12.5e-6`. Zero is written as `0e0`.
+    pub(crate) fn write_exp<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        exponent_step: i32,
+        min_fraction_digits: usize,
+        upper: bool,
+    ) -> fmt::Result {
+        if self.is_negative() {
+            w.write_char('-')?;
+        }
+
+        let digits = &self.digits[..self.len];
+        let exponent = match self.is_zero() {
+            true => 0,
+            false => (self.point - 1).div_euclid(exponent_step) * exponent_step,
+        };
+        // Between 1 and `exponent_step` digits before the point, none for zero
+        let integer_digits = (self.point - exponent) as usize;
+
+        let fraction_digits = if self.is_zero() {
+            w.write_char('0')?;
+            0
+        } else if integer_digits >= self.len {
+            write_ascii(w, digits)?;
+            write_zeros(w, integer_digits - self.len)?;
+            0
+        } else {
+            write_ascii(w, &digits[..integer_digits])?;
+            w.write_char('.')?;
+            write_ascii(w, &digits[integer_digits..])?;
+            self.len - integer_digits
+        };
+
+        if fraction_digits == 0 && min_fraction_digits > 0 {
+            w.write_char('.')?;
+        }
+        write_zeros(w, min_fraction_digits.saturating_sub(fraction_digits))?;
+
+        w.write_char(if upper { 'E' } else { 'e' })?;
+        write!(w, "{}", exponent)
+    }
+}
+
+#[inline]
+fn write_ascii<W: Write + ?Sized>(w: &mut W, digits: &[u8]) -> fmt::Result {
+    // SAFETY: digits are always ASCII
+    w.write_str(unsafe { std::str::from_utf8_unchecked(digits) })
+}
+
+#[inline]
+fn write_zeros<W: Write + ?Sized>(w: &mut W, count: usize) -> fmt::Result {
+    const ZEROS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+    let mut remaining = count;
+    while remaining > 0 {
+        let chunk = remaining.min(ZEROS.len());
+        w.write_str(&ZEROS[..chunk])?;
+        remaining -= chunk;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn fixed(decimal: &Decimal, min_fraction_digits: usize) -> String {
+        let mut output = String::new();
+        decimal
+            .write_fixed(&mut output, min_fraction_digits)
+            .unwrap();
+        output
+    }
+
+    fn rounded(input: &str, fraction_digits: usize) -> String {
+        let mut decimal = Decimal::parse(input.as_bytes());
+        decimal.round_fraction(fraction_digits, RoundingMode::HalfEven);
+        fixed(&decimal, 0)
+    }
+
+    #[test]
+    fn test_parse_and_write() {
+        let cases = [
+            ("0.0", "0"),
+            ("-0.0", "0"),
+            ("1.0", "1"),
+            ("123.456", "123.456"),
+            ("-0.00123", "-0.00123"),
+            ("1e-7", "0.0000001"),
+            ("1.5e16", "15000000000000000"),
+            ("-2.5e-3", "-0.0025"),
+            ("00120.0500", "120.05"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(fixed(&Decimal::parse(input.as_bytes()), 0), *expected);
+        }
+    }
+
+    #[test]
+    fn test_from_shortest() {
+        assert_eq!(fixed(&Decimal::from_shortest(0.1_f64), 0), "0.1");
+        assert_eq!(fixed(&Decimal::from_shortest(0.1_f32), 0), "0.1");
+        assert_eq!(
+            fixed(&Decimal::from_shortest(0.1_f32 as f64), 0),
+            "0.10000000149011612"
+        );
+        assert_eq!(
+            fixed(&Decimal::from_shortest(-1.5e-7_f32), 0),
+            "-0.00000015"
+        );
+    }
+
+    #[test]
+    fn test_write_min_fraction_digits() {
+        assert_eq!(fixed(&Decimal::parse(b"1.5"), 3), "1.500");
+        assert_eq!(fixed(&Decimal::parse(b"100"), 2), "100.00");
+        assert_eq!(fixed(&Decimal::parse(b"0.0"), 2), "0.00");
+        assert_eq!(fixed(&Decimal::parse(b"0.001"), 2), "0.001");
+        assert_eq!(fixed(&Decimal::parse(b"-0.25"), 4), "-0.2500");
+    }
+
+    #[test]
+    fn test_round_fraction_half_even() {
+        assert_eq!(rounded("1.234", 2), "1.23");
+        assert_eq!(rounded("1.236", 2), "1.24");
+        assert_eq!(rounded("1.2351", 2), "1.24");
+        assert_eq!(rounded("1.235", 2), "1.24");
+        assert_eq!(rounded("1.245", 2), "1.24");
+        assert_eq!(rounded("2.5", 0), "2");
+        assert_eq!(rounded("3.5", 0), "4");
+        assert_eq!(rounded("0.5", 0), "0");
+        assert_eq!(rounded("0.51", 0), "1");
+        assert_eq!(rounded("9.995", 2), "10");
+        assert_eq!(rounded("-9.996", 2), "-10");
+        assert_eq!(rounded("0.0004", 3), "0");
+        assert_eq!(rounded("0.0006", 3), "0.001");
+        assert_eq!(rounded("0.00096", 3), "0.001");
+        assert_eq!(rounded("0.000096", 3), "0");
+        assert_eq!(rounded("99.9", 0), "100");
+        assert_eq!(rounded("123.456", 5), "123.456");
+    }
+
+    #[test]
+    fn test_from_float_matches_core() {
+        let values = [
+            0.1 + 0.2,
+            2.675,
+            0.125,
+            0.375,
+            1.5,
+            2.5,
+            0.9999999999999999,
+            4.000000000000001,
+            1234.5678,
+            1e-10,
+        ];
+
+        for value in values {
+            for precision in 0..16 {
+                // Beyond 15 significant digits the exact binary expansion shows through
+                if value >= 10f64.powi(15 - precision as i32) {
+                    continue;
+                }
+
+                let mut expected = format!("{:.*}", precision, value);
+                if expected.contains('.') {
+                    expected = expected
+                        .trim_end_matches('0')
+                        .trim_end_matches('.')
+                        .to_string();
+                }
+                if expected.bytes().all(|b| matches!(b, b'-' | b'0' | b'.')) {
+                    expected = "0".to_string();
+                }
+
+                let actual = fixed(&Decimal::from_float(value, precision), 0);
+                assert_eq!(actual, expected, "Failed for {} at {}", value, precision);
+            }
+        }
+    }
+
+    fn exp(input: &str, exponent_step: i32, min_fraction_digits: usize) -> String {
+        let mut output = String::new();
+        Decimal::parse(input.as_bytes())
+            .write_exp(&mut output, exponent_step, min_fraction_digits, false)
+            .unwrap();
+        output
+    }
+
+    fn exp_of(decimal: &Decimal) -> String {
+        let mut output = String::new();
+        decimal.write_exp(&mut output, 1, 0, false).unwrap();
+        output
+    }
+
+    #[test]
+    fn test_write_exp() {
+        assert_eq!(exp("0.0", 1, 0), "0e0");
+        assert_eq!(exp("0.0", 1, 2), "0.00e0");
+        assert_eq!(exp("1.0", 1, 0), "1e0");
+        assert_eq!(exp("1234.5", 1, 0), "1.2345e3");
+        assert_eq!(exp("-0.00032", 1, 0), "-3.2e-4");
+        assert_eq!(exp("3.2e-14", 1, 3), "3.200e-14");
+        assert_eq!(exp("1e300", 1, 0), "1e300");
+    }
+
+    #[test]
+    fn test_write_engineering() {
+        assert_eq!(exp("0.0", 3, 0), "0e0");
+        assert_eq!(exp("1.5", 3, 0), "1.5e0");
+        assert_eq!(exp("1234.5", 3, 0), "1.2345e3");
+        assert_eq!(exp("12345", 3, 0), "12.345e3");
+        assert_eq!(exp("100000", 3, 0), "100e3");
+        assert_eq!(exp("0.00032", 3, 0), "320e-6");
+        assert_eq!(exp("-0.0032", 3, 0), "-3.2e-3");
+        assert_eq!(exp("3.2e-14", 3, 0), "32e-15");
+    }
+
+    #[test]
+    fn test_round_significant() {
+        let mut decimal = Decimal::parse(b"0.30000000000000004");
+        decimal.round_significant(15, RoundingMode::HalfEven);
+        assert_eq!(fixed(&decimal, 0), "0.3");
+
+        let mut decimal = Decimal::parse(b"99.96");
+        decimal.round_significant(3, RoundingMode::HalfEven);
+        assert_eq!(fixed(&decimal, 0), "100");
+
+        let mut decimal = Decimal::parse(b"1.25e300");
+        decimal.round_significant(2, RoundingMode::HalfEven);
+        assert_eq!(exp_of(&decimal), "1.2e300");
+    }
+
+    #[test]
+    fn test_round_fraction_modes() {
+        let round = |input: &str, fraction_digits, mode| {
+            let mut decimal = Decimal::parse(input.as_bytes());
+            decimal.round_fraction(fraction_digits, mode);
+            fixed(&decimal, 0)
+        };
+
+        let cases = [
+            // input, HalfUp, HalfDown, HalfEven, Up, Down, Ceiling, Floor
+            (
+                "2.675",
+                ["2.68", "2.67", "2.68", "2.68", "2.67", "2.68", "2.67"],
+            ),
+            (
+                "2.665",
+                ["2.67", "2.66", "2.66", "2.67", "2.66", "2.67", "2.66"],
+            ),
+            (
+                "-2.665",
+                [
+                    "-2.67", "-2.66", "-2.66", "-2.67", "-2.66", "-2.66", "-2.67",
+                ],
+            ),
+            (
+                "2.6651",
+                ["2.67", "2.67", "2.67", "2.67", "2.66", "2.67", "2.66"],
+            ),
+            (
+                "2.6649",
+                ["2.66", "2.66", "2.66", "2.67", "2.66", "2.67", "2.66"],
+            ),
+            ("0.0001", ["0", "0", "0", "0.01", "0", "0.01", "0"]),
+            ("-0.0001", ["0", "0", "0", "-0.01", "0", "0", "-0.01"]),
+            ("9.999", ["10", "10", "10", "10", "9.99", "10", "9.99"]),
+            ("1.5", ["1.5", "1.5", "1.5", "1.5", "1.5", "1.5", "1.5"]),
+        ];
+        let modes = [
+            RoundingMode::HalfUp,
+            RoundingMode::HalfDown,
+            RoundingMode::HalfEven,
+            RoundingMode::Up,
+            RoundingMode::Down,
+            RoundingMode::Ceiling,
+            RoundingMode::Floor,
+        ];
+
+        for (input, expected) in cases {
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                assert_eq!(round(input, 2, mode), expected, "{} {:?}", input, mode);
+            }
+        }
+
+        assert_eq!(round("0.4", 0, RoundingMode::Up), "1");
+        assert_eq!(round("0.04", 0, RoundingMode::Ceiling), "1");
+        assert_eq!(round("-0.04", 0, RoundingMode::Floor), "-1");
+    }
+}
@@ -0,0 +1,363 @@
+use std::fmt::{self, Write};
+
+use super::decimal::Decimal;
+use super::{RoundingMode, TolerantFloat};
+use crate::traits::{DisplayableFloat, Float, Tolerance};
+
+/// What to do with trailing zeros in the fractional part
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TrailingZeros {
+    /// Remove trailing zeros down to the minimum number of fractional digits
+    #[default]
+    Trim,
+    /// Pad with zeros up to the maximum number of fractional digits
+    Keep,
+}
+
+/// When to write the sign of a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SignPolicy {
+    /// Only negative values have a sign
+    #[default]
+    Negative,
+    /// Positive values and zero are written with `+`
+    Always,
+    /// Positive values are written with `+`, zero has no sign
+    NonZero,
+}
+
+/// Output policy for [`TolerantFloat::format_with`].
+///
+/// The default spec produces the same output as [`TolerantFloat::format`]: the value
+/// is rounded at its dynamic precision, trailing zeros are trimmed and values within
+/// epsilon are written as zero. Each builder method changes one aspect of that.
+///
+/// The number of fractional digits is always bounded by the dynamic precision
+/// ([`Float::precision`]), digits beyond it are floating-point artifacts. They are
+/// written as zeros when [`TrailingZeros::Keep`] pads past it.
+///
+/// # Examples
+///
+/// ```
+/// use extended_float::types::ExtendedFloat;
+/// use extended_float::types::extended_float::{FormatSpec, RoundingMode, TrailingZeros};
+///
+/// let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+///
+/// // Exactly 2 decimals for a UI
+/// let ui = FormatSpec::new().fraction_digits(2).trailing_zeros(TrailingZeros::Keep);
+/// assert_eq!(value.format_with(&ui), "0.30");
+///
+/// // Between 1 and 8 decimals for an exchange
+/// let exchange = FormatSpec::new().min_fraction_digits(1).max_fraction_digits(8);
+/// assert_eq!(value.format_with(&exchange), "0.3");
+/// assert_eq!(ExtendedFloat::new(2.0).format_with(&exchange), "2.0");
+/// assert_eq!(ExtendedFloat::new(1.123456789).format_with(&exchange), "1.12345679");
+///
+/// // Truncated instead of rounded
+/// let truncated = FormatSpec::new()
+///     .max_fraction_digits(2)
+///     .rounding(RoundingMode::Down);
+/// assert_eq!(ExtendedFloat::new(2.679).format_with(&truncated), "2.67");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FormatSpec {
+    min_fraction_digits: usize,
+    max_fraction_digits: Option<usize>,
+    trailing_zeros: TrailingZeros,
+    zero_threshold: Option<f64>,
+    sign: SignPolicy,
+    rounding: RoundingMode,
+}
+
+impl FormatSpec {
+    /// Creates a spec producing the same output as [`TolerantFloat::format`]
+    pub const fn new() -> Self {
+        Self {
+            min_fraction_digits: 0,
+            max_fraction_digits: None,
+            trailing_zeros: TrailingZeros::Trim,
+            zero_threshold: None,
+            sign: SignPolicy::Negative,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Sets the number of fractional digits that are always written, padding with zeros
+    #[must_use]
+    pub const fn min_fraction_digits(mut self, digits: usize) -> Self {
+        self.min_fraction_digits = digits;
+        self
+    }
+
+    /// Sets the number of fractional digits the value is rounded at.
+    ///
+    /// The dynamic precision of the value still applies if it's lower.
+    /// A maximum below the minimum is raised to it.
+    #[must_use]
+    pub const fn max_fraction_digits(mut self, digits: usize) -> Self {
+        self.max_fraction_digits = Some(digits);
+        self
+    }
+
+    /// Sets both the minimum and maximum number of fractional digits
+    #[must_use]
+    pub const fn fraction_digits(self, digits: usize) -> Self {
+        self.min_fraction_digits(digits).max_fraction_digits(digits)
+    }
+
+    /// Sets whether trailing zeros are trimmed or kept
+    #[must_use]
+    pub const fn trailing_zeros(mut self, trailing_zeros: TrailingZeros) -> Self {
+        self.trailing_zeros = trailing_zeros;
+        self
+    }
+
+    /// Sets the magnitude at or below which values are written as zero.
+    ///
+    /// Defaults to the epsilon of the float type. A threshold of `0.0` writes every
+    /// non-zero value, although it can still round to zero.
+    #[must_use]
+    pub const fn zero_threshold(mut self, threshold: f64) -> Self {
+        self.zero_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets when the sign is written
+    #[must_use]
+    pub const fn sign(mut self, sign: SignPolicy) -> Self {
+        self.sign = sign;
+        self
+    }
+
+    /// Sets how the value is rounded to the maximum number of fractional digits
+    #[must_use]
+    pub const fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
+    /// Formats the value according to `spec`.
+    ///
+    /// See [`FormatSpec`] for the available options.
+    pub fn format_with(&self, spec: &FormatSpec) -> String {
+        let mut formatted = String::with_capacity(32);
+        self.format_with_to(spec, &mut formatted).unwrap();
+        formatted
+    }
+
+    /// Writes the value formatted according to `spec` without allocating.
+    pub fn format_with_to<W: Write>(&self, spec: &FormatSpec, w: &mut W) -> fmt::Result {
+        let value = self.downgrade();
+
+        // Only reachable through `new_unchecked`, keep the primitive representation
+        if !value.is_finite() {
+            return write!(w, "{}", value);
+        }
+
+        let zero_threshold = match spec.zero_threshold {
+            Some(threshold) => T::from(threshold).unwrap_or_else(T::infinity),
+            None => <T as Float>::epsilon(),
+        };
+
+        let min_fraction_digits = spec.min_fraction_digits;
+        let max_fraction_digits = spec
+            .max_fraction_digits
+            .map(|digits| digits.max(min_fraction_digits));
+
+        let (decimal, padded_digits) = if value.abs() <= zero_threshold {
+            (Decimal::ZERO, max_fraction_digits)
+        } else if value.abs() > T::decimal_precision() {
+            // There is no meaningful precision to bound the digits, the value is as-is
+            let mut decimal = Decimal::from_display(value);
+            if let Some(digits) = max_fraction_digits {
+                decimal.round_fraction(digits, spec.rounding);
+            }
+            (decimal, max_fraction_digits)
+        } else {
+            let precision = value.precision();
+            let mut decimal = Decimal::from_float(value, precision);
+            let digits = max_fraction_digits.unwrap_or(precision);
+            decimal.round_fraction(digits.min(precision), spec.rounding);
+            (decimal, Some(digits))
+        };
+
+        let fraction_digits = match spec.trailing_zeros {
+            TrailingZeros::Trim => min_fraction_digits,
+            TrailingZeros::Keep => padded_digits.unwrap_or(min_fraction_digits),
+        };
+
+        let plus = match spec.sign {
+            SignPolicy::Negative => false,
+            SignPolicy::Always => !decimal.is_negative(),
+            SignPolicy::NonZero => !decimal.is_negative() && !decimal.is_zero(),
+        };
+        if plus {
+            w.write_char('+')?;
+        }
+
+        decimal.write_fixed(w, fraction_digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::types::ExtendedFloat;
+
+    const VALUES: [f64; 14] = [
+        0.0,
+        -0.0,
+        1e-13,
+        0.1,
+        -0.25,
+        123.4560,
+        1234.50,
+        100.0,
+        0.30000000000000004,
+        0.9999999999999999,
+        4.00000000000001,
+        1e15,
+        1e300,
+        f64::MIN,
+    ];
+
+    #[test]
+    fn test_default_spec_matches_format() {
+        let spec = FormatSpec::new();
+        assert_eq!(spec, FormatSpec::default());
+
+        for value in VALUES {
+            let value = ExtendedFloat::new(value);
+            assert_eq!(value.format_with(&spec), value.format());
+        }
+
+        for value in [0.1_f32 + 0.2_f32, 1125866.3, f32::MAX, -1e-7] {
+            let value = ExtendedFloat::new(value);
+            assert_eq!(value.format_with(&spec), value.format());
+        }
+    }
+
+    #[test]
+    fn test_fraction_digits() {
+        let exact = FormatSpec::new()
+            .fraction_digits(2)
+            .trailing_zeros(TrailingZeros::Keep);
+        assert_eq!(ExtendedFloat::new(1.5).format_with(&exact), "1.50");
+        assert_eq!(ExtendedFloat::new(0.0).format_with(&exact), "0.00");
+        assert_eq!(ExtendedFloat::new(2.675).format_with(&exact), "2.68");
+        assert_eq!(ExtendedFloat::new(-0.001).format_with(&exact), "0.00");
+        assert_eq!(ExtendedFloat::new(9.999).format_with(&exact), "10.00");
+        assert_eq!(
+            ExtendedFloat::new(1e20).format_with(&exact),
+            "100000000000000000000.00"
+        );
+
+        let range = FormatSpec::new()
+            .min_fraction_digits(1)
+            .max_fraction_digits(8);
+        assert_eq!(ExtendedFloat::new(2.0).format_with(&range), "2.0");
+        assert_eq!(ExtendedFloat::new(2.5).format_with(&range), "2.5");
+        assert_eq!(
+            ExtendedFloat::new(0.123456789).format_with(&range),
+            "0.12345679"
+        );
+        assert_eq!(ExtendedFloat::new(1e-9).format_with(&range), "0.0");
+
+        // The maximum is raised to the minimum
+        let spec = FormatSpec::new()
+            .min_fraction_digits(3)
+            .max_fraction_digits(1);
+        assert_eq!(ExtendedFloat::new(1.23456).format_with(&spec), "1.235");
+    }
+
+    #[test]
+    fn test_precision_is_upper_bound() {
+        let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+
+        // Digits past the dynamic precision are never taken from the float
+        let spec = FormatSpec::new().max_fraction_digits(20);
+        assert_eq!(value.format_with(&spec), "0.3");
+
+        let spec = spec.trailing_zeros(TrailingZeros::Keep);
+        assert_eq!(value.format_with(&spec), "0.30000000000000000000");
+
+        // Without a maximum, zeros are kept up to the dynamic precision
+        let spec = FormatSpec::new().trailing_zeros(TrailingZeros::Keep);
+        assert_eq!(value.format_with(&spec), "0.300000000000000");
+        assert_eq!(ExtendedFloat::new(0.5_f32).format_with(&spec), "0.500000");
+    }
+
+    #[test]
+    fn test_zero_threshold() {
+        let value = ExtendedFloat::new(3.2e-14);
+        assert_eq!(value.format_with(&FormatSpec::new()), "0");
+
+        let spec = FormatSpec::new().zero_threshold(0.0);
+        assert_eq!(value.format_with(&spec), "0.000000000000032");
+
+        let spec = FormatSpec::new().zero_threshold(0.01);
+        assert_eq!(ExtendedFloat::new(0.01).format_with(&spec), "0");
+        assert_eq!(ExtendedFloat::new(-0.005).format_with(&spec), "0");
+        assert_eq!(ExtendedFloat::new(0.011).format_with(&spec), "0.011");
+    }
+
+    #[test]
+    fn test_sign_policy() {
+        let always = FormatSpec::new().sign(SignPolicy::Always);
+        assert_eq!(ExtendedFloat::new(1.5).format_with(&always), "+1.5");
+        assert_eq!(ExtendedFloat::new(-1.5).format_with(&always), "-1.5");
+        assert_eq!(ExtendedFloat::new(0.0).format_with(&always), "+0");
+        assert_eq!(ExtendedFloat::new(-1e-13).format_with(&always), "+0");
+
+        let non_zero = FormatSpec::new().sign(SignPolicy::NonZero);
+        assert_eq!(ExtendedFloat::new(1.5).format_with(&non_zero), "+1.5");
+        assert_eq!(ExtendedFloat::new(-1.5).format_with(&non_zero), "-1.5");
+        assert_eq!(ExtendedFloat::new(0.0).format_with(&non_zero), "0");
+
+        // Values rounded to zero lose their sign
+        let spec = non_zero.max_fraction_digits(1);
+        assert_eq!(ExtendedFloat::new(0.04).format_with(&spec), "0");
+        assert_eq!(ExtendedFloat::new(-0.04).format_with(&spec), "0");
+    }
+
+    #[test]
+    fn test_rounding_mode() {
+        let spec = |rounding| FormatSpec::new().max_fraction_digits(2).rounding(rounding);
+
+        let value = ExtendedFloat::new(2.675);
+        assert_eq!(value.format_with(&spec(RoundingMode::HalfUp)), "2.68");
+        assert_eq!(value.format_with(&spec(RoundingMode::HalfDown)), "2.67");
+        assert_eq!(value.format_with(&spec(RoundingMode::HalfEven)), "2.68");
+        assert_eq!(value.format_with(&spec(RoundingMode::Down)), "2.67");
+
+        let value = ExtendedFloat::new(-1.001);
+        assert_eq!(value.format_with(&spec(RoundingMode::Up)), "-1.01");
+        assert_eq!(value.format_with(&spec(RoundingMode::Down)), "-1");
+        assert_eq!(value.format_with(&spec(RoundingMode::Ceiling)), "-1");
+        assert_eq!(value.format_with(&spec(RoundingMode::Floor)), "-1.01");
+
+        // Artifacts don't leak into directed rounding
+        let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+        assert_eq!(value.format_with(&spec(RoundingMode::Up)), "0.3");
+        let value = ExtendedFloat::new(0.7) - ExtendedFloat::new(0.4);
+        assert_eq!(value.format_with(&spec(RoundingMode::Down)), "0.3");
+    }
+
+    #[test]
+    fn test_format_with_to() {
+        let spec = FormatSpec::new()
+            .fraction_digits(3)
+            .trailing_zeros(TrailingZeros::Keep)
+            .sign(SignPolicy::Always);
+        let mut output = String::new();
+        ExtendedFloat::new(12.5)
+            .format_with_to(&spec, &mut output)
+            .unwrap();
+        assert_eq!(output, "+12.500");
+    }
+}
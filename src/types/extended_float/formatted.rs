@@ -0,0 +1,496 @@
+use core::hint::unlikely;
+use std::cmp::Ordering;
+use std::fmt::{self, Write};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use super::TolerantFloat;
+use super::decimal::Decimal;
+use crate::traits::{DisplayableFloat, Float, Tolerance};
+
+/// Maximum length of [`TolerantFloat::format`] output for any supported float type.
+///
+/// Values above the decimal precision threshold are formatted as-is, so the longest
+/// output is `f64::MIN` written out in full: a sign and 309 digits.
+pub const FORMAT_BUFFER_LEN: usize = f64::MAX_10_EXP as usize + 2;
+
+/// Stack buffer holding the output of [`TolerantFloat::format`].
+///
+/// Created by [`TolerantFloat::formatted`], it dereferences to `str` and never allocates.
+#[derive(Clone, Copy)]
+pub struct FormattedFloat {
+    buffer: [u8; FORMAT_BUFFER_LEN],
+    len: usize,
+}
+
+impl FormattedFloat {
+    /// Returns the formatted value as a string slice
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the buffer is only ever filled by `write_formatted`, which writes UTF-8
+        unsafe { std::str::from_utf8_unchecked(&self.buffer[..self.len]) }
+    }
+}
+
+impl Deref for FormattedFloat {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for FormattedFloat {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for FormattedFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl fmt::Debug for FormattedFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for FormattedFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for FormattedFloat {}
+
+impl Hash for FormattedFloat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for FormattedFloat {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for FormattedFloat {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+/// `fmt::Write` adapter over a byte slice that fails instead of growing
+pub(crate) struct SliceWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    #[inline]
+    pub(crate) fn new(buffer: &'a mut [u8]) -> Self {
+        Self { buffer, len: 0 }
+    }
+
+    /// Number of bytes written so far
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the bytes written so far as a string slice
+    #[inline]
+    pub(crate) fn as_str(&self) -> &str {
+        // SAFETY: only `&str` is ever written into the buffer
+        unsafe { std::str::from_utf8_unchecked(&self.buffer[..self.len]) }
+    }
+}
+
+impl Write for SliceWriter<'_> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        let target = self.buffer.get_mut(self.len..end).ok_or(fmt::Error)?;
+        target.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
+    /// Formats the value like [`format`](Self::format) into a stack buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+    /// assert_eq!(value.formatted(), "0.3");
+    /// assert_eq!(value.formatted().len(), 3);
+    /// ```
+    #[inline]
+    pub fn formatted(&self) -> FormattedFloat {
+        let mut formatted = FormattedFloat {
+            buffer: [0; FORMAT_BUFFER_LEN],
+            len: 0,
+        };
+        // The buffer fits the longest possible output
+        formatted.len = self.write_formatted(&mut formatted.buffer).unwrap();
+        formatted
+    }
+
+    /// Writes the value formatted like [`format`](Self::format) without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let mut line = String::with_capacity(64);
+    /// ExtendedFloat::new(101.25).format_to(&mut line).unwrap();
+    /// assert_eq!(line, "101.25");
+    /// ```
+    #[inline]
+    pub fn format_to<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(self.formatted().as_str())
+    }
+
+    /// Formats the value like [`format`](Self::format) into a caller-provided buffer.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the buffer is too small for the output. A buffer of
+    /// [`FORMAT_BUFFER_LEN`] bytes always suffices.
+    /// For a non-panicking version, use `try_format_into`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let mut buffer = [0u8; 32];
+    /// assert_eq!(ExtendedFloat::new(1.5).format_into(&mut buffer), "1.5");
+    /// ```
+    #[inline]
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        self.try_format_into(buf)
+            .expect("Buffer is too small for the formatted ExtendedFloat")
+    }
+
+    /// Formats the value like [`format`](Self::format) into a caller-provided buffer,
+    /// returning an error if the buffer is too small.
+    #[inline]
+    pub fn try_format_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, fmt::Error> {
+        let len = self.write_formatted(buf)?;
+        // SAFETY: `write_formatted` writes UTF-8 only
+        Ok(unsafe { std::str::from_utf8_unchecked(&buf[..len]) })
+    }
+
+    /// Returns true if the values format identically with [`format`](Self::format).
+    ///
+    /// This is the equality users see: it holds exactly when `a.format() == b.format()`,
+    /// without allocating. It differs from `==`, which uses the tolerance instead of
+    /// the shown digits, e.g. `100.000000000049` and `100.000000000051` are `==` but
+    /// show different digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let sum = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+    /// assert!(sum.eq_display(&ExtendedFloat::new(0.3)));
+    /// assert!(!ExtendedFloat::new(100.000000000049).eq_display(&ExtendedFloat::new(100.000000000051)));
+    /// ```
+    #[inline]
+    pub fn eq_display(&self, other: &Self) -> bool {
+        self.formatted() == other.formatted()
+    }
+
+    /// Compares the values as shown by [`format`](Self::format).
+    ///
+    /// The order of the shown decimals, `Equal` exactly when
+    /// [`eq_display`](Self::eq_display) holds. It is a total order, so it's safe for
+    /// sorting and ordered collections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let sum = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+    /// assert_eq!(sum.cmp_display(&ExtendedFloat::new(0.3)), Ordering::Equal);
+    /// assert_eq!(ExtendedFloat::new(-2.5).cmp_display(&ExtendedFloat::new(-10.0)), Ordering::Greater);
+    /// ```
+    pub fn cmp_display(&self, other: &Self) -> Ordering {
+        compare_shown(self.formatted().as_str(), other.formatted().as_str())
+    }
+
+    /// Writes the formatted value to the start of `buffer`, returning its length.
+    ///
+    /// This is the single implementation behind all formatting methods, see
+    /// [`format`](Self::format) for the rules.
+    fn write_formatted(&self, buffer: &mut [u8]) -> Result<usize, fmt::Error> {
+        let value = self.downgrade();
+        let mut writer = SliceWriter::new(buffer);
+
+        // Only reachable through `new_unchecked`, keep the primitive representation
+        if unlikely(!value.is_finite()) {
+            write!(writer, "{}", value)?;
+            return Ok(writer.len);
+        }
+
+        // Handle special case of zero
+        if value.abs() <= <T as Float>::epsilon() {
+            writer.write_str("0")?;
+            return Ok(writer.len);
+        }
+
+        // if value is too big, just return it as is
+        if value.abs() > T::decimal_precision() {
+            write!(writer, "{}", value)?;
+            return Ok(writer.len);
+        }
+
+        let decimal = Decimal::from_float(value, value.precision());
+        decimal.write_fixed(&mut writer, 0)?;
+        Ok(writer.len)
+    }
+}
+
+/// Compares two outputs of `write_formatted` as decimal numbers.
+///
+/// The outputs are plain decimals without exponent, leading zeros in the integer part
+/// or trailing zeros in the fraction, so digit strings of the same length compare like
+/// the numbers.
+fn compare_shown(a: &str, b: &str) -> Ordering {
+    let (a_negative, a_integer, a_fraction) = split_shown(a);
+    let (b_negative, b_integer, b_fraction) = split_shown(b);
+
+    let magnitude = a_integer
+        .len()
+        .cmp(&b_integer.len())
+        .then_with(|| a_integer.cmp(b_integer))
+        .then_with(|| a_fraction.cmp(b_fraction));
+    match (a_negative, b_negative) {
+        (false, false) => magnitude,
+        (true, true) => magnitude.reverse(),
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    }
+}
+
+/// Splits a formatted value into whether it's below zero, the integer digits and the
+/// fraction digits
+fn split_shown(shown: &str) -> (bool, &str, &str) {
+    let (negative, digits) = match shown.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, shown),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let is_zero = integer.bytes().chain(fraction.bytes()).all(|c| c == b'0');
+    (negative && !is_zero, integer, fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::test_utils::generator;
+    use crate::types::ExtendedFloat;
+
+    const VALUES: [f64; 14] = [
+        0.0,
+        -0.0,
+        1e-13,
+        0.1,
+        -0.25,
+        123.4560,
+        1234.50,
+        100.0,
+        0.30000000000000004,
+        0.9999999999999999,
+        4.00000000000001,
+        1e15,
+        1e300,
+        f64::MIN,
+    ];
+
+    #[test]
+    fn test_formatted_matches_format() {
+        for value in VALUES {
+            let value = ExtendedFloat::new(value);
+            assert_eq!(value.formatted().as_str(), value.format());
+        }
+
+        let value = ExtendedFloat::new(f32::MIN);
+        assert_eq!(value.formatted().as_str(), value.format());
+    }
+
+    #[test]
+    fn test_format_to_matches_format() {
+        let mut output = String::new();
+        for value in VALUES {
+            let value = ExtendedFloat::new(value);
+            output.clear();
+            value.format_to(&mut output).unwrap();
+            assert_eq!(output, value.format());
+        }
+    }
+
+    #[test]
+    fn test_format_into_matches_format() {
+        let mut buffer = [0u8; FORMAT_BUFFER_LEN];
+        for value in VALUES {
+            let value = ExtendedFloat::new(value);
+            assert_eq!(value.format_into(&mut buffer), value.format());
+        }
+    }
+
+    #[test]
+    fn test_format_into_small_buffer() {
+        let value = ExtendedFloat::new(123.456);
+        let mut buffer = [0u8; 7];
+        assert_eq!(value.try_format_into(&mut buffer), Ok("123.456"));
+
+        let mut buffer = [0u8; 6];
+        assert_eq!(value.try_format_into(&mut buffer), Err(fmt::Error));
+    }
+
+    #[test]
+    #[should_panic(expected = "Buffer is too small for the formatted ExtendedFloat")]
+    fn test_format_into_panics_on_small_buffer() {
+        let mut buffer = [0u8; 2];
+        ExtendedFloat::new(123.456).format_into(&mut buffer);
+    }
+
+    #[test]
+    fn test_formatted_float_traits() {
+        let formatted = ExtendedFloat::new(-1.5).formatted();
+        assert_eq!(formatted, "-1.5");
+        assert_eq!(formatted.len(), 4);
+        assert!(formatted.starts_with('-'));
+        assert_eq!(format!("{:>6}", formatted), "  -1.5");
+        assert_eq!(format!("{:?}", formatted), "\"-1.5\"");
+        assert_eq!(formatted, ExtendedFloat::new(-1.5).formatted());
+    }
+
+    /// The formatting algorithm before the shortest-representation path: exact digits
+    /// at the dynamic precision from core formatting, with trailing zeros trimmed
+    fn legacy_format<T: DisplayableFloat>(value: T) -> String {
+        if value.abs() <= <T as Float>::epsilon() {
+            return "0".to_string();
+        }
+        if value.abs() > T::decimal_precision() {
+            return value.to_string();
+        }
+
+        let formatted = format!("{:.*}", value.precision(), value);
+        match formatted.contains('.') {
+            true => {
+                formatted
+                    .trim_end_matches('0')
+                    .trim_end_matches('.')
+                    .to_string()
+            }
+            false => formatted,
+        }
+    }
+
+    #[test]
+    fn test_formatted_matches_legacy_algorithm() {
+        let mut next = generator(0x2545_F491_4F6C_DD1D);
+
+        let mut buffer = [0u8; FORMAT_BUFFER_LEN];
+        for _ in 0..200_000 {
+            // Arbitrary bit patterns cover all magnitudes, short decimals with
+            // accumulated artifacts cover ties and values like 0.1 + 0.2
+            let value = match next() % 3 {
+                0 => f64::from_bits(next()),
+                1 => (next() % 2_000_000) as f64 / 10f64.powi((next() % 12) as i32) - 1000.0,
+                _ => (0..(next() % 5)).fold(0.0, |sum, _| sum + (next() % 1000) as f64 / 100.0),
+            };
+            if !value.is_finite() {
+                continue;
+            }
+
+            let expected = legacy_format(value);
+            assert_eq!(
+                ExtendedFloat::new(value).format_into(&mut buffer),
+                expected,
+                "Failed for {:e}",
+                value
+            );
+
+            let value = value as f32;
+            if value.is_finite() {
+                assert_eq!(
+                    ExtendedFloat::new(value).format_into(&mut buffer),
+                    legacy_format(value),
+                    "Failed for {:e}",
+                    value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_eq_display() {
+        for a in VALUES {
+            for b in VALUES {
+                let (a, b) = (ExtendedFloat::new(a), ExtendedFloat::new(b));
+                assert_eq!(a.eq_display(&b), a.format() == b.format(), "{} {}", a, b);
+            }
+        }
+
+        let sum = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+        assert!(sum.eq_display(&ExtendedFloat::new(0.3)));
+        assert!(ExtendedFloat::new(-1e-12).eq_display(&ExtendedFloat::new(1e-12)));
+        assert!(!ExtendedFloat::new(0.1_f32).eq_display(&ExtendedFloat::new(0.100001_f32)));
+    }
+
+    #[test]
+    fn test_cmp_display() {
+        let ordered = [
+            "-100", "-10.5", "-10", "-9.99", "-0.5", "-0.05", "0", "0.05", "0.1", "0.15", "1",
+            "9.5", "10", "10.01", "123",
+        ];
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert_eq!(compare_shown(a, b), i.cmp(&j), "{} {}", a, b);
+            }
+        }
+        assert_eq!(compare_shown("-0", "0"), Ordering::Equal);
+
+        let mut next = generator(0x9E37_79B9_7F4A_7C15);
+
+        let mut values: Vec<_> = (0..2_000)
+            .map(|_| {
+                let value = match next() % 3 {
+                    0 => (next() % 2_000_000) as f64 / 10f64.powi((next() % 12) as i32) - 1000.0,
+                    1 => f64::from_bits(0.3f64.to_bits() + next() % 64) * (next() % 3) as f64,
+                    _ => (next() % 2001) as f64 * 1e-15 - 1e-12,
+                };
+                ExtendedFloat::new(value)
+            })
+            .collect();
+        values.sort_by(|a, b| a.cmp_display(b));
+
+        for pair in values.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert_eq!(a.cmp_display(&b) == Ordering::Equal, a.eq_display(&b));
+            assert_ne!(a.cmp_display(&b), Ordering::Greater);
+            assert!(a.format().parse::<f64>().unwrap() <= b.format().parse::<f64>().unwrap());
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use super::wrapper::impl_wrapper;
+use super::{DefaultTolerance, TolerantFloat};
+use crate::traits::{DisplayableFloat, Tolerance};
+
+/// Wrapper comparing [`ExtendedFloat`](super::ExtendedFloat) by its displayed value,
+/// for `HashMap` and `HashSet` keys and anywhere "prints the same" should mean "equal".
+///
+/// `ExtendedFloat` itself doesn't implement `Hash`: its `==` accepts values within
+/// epsilon of each other, and no hash can put every such pair into the same bucket
+/// without putting all values there. This wrapper instead quantizes the value to the
+/// display precision: two keys are equal exactly when [`format`](TolerantFloat::format)
+/// gives the same text ([`eq_display`](TolerantFloat::eq_display)), the hash is
+/// computed from that text and the order is the order of the shown decimals
+/// ([`cmp_display`](TolerantFloat::cmp_display)). The formatting happens on the
+/// stack, nothing is allocated.
+///
+/// Edge cases:
+/// - Values that `==` accepts but that round to different shown digits are different
+///   keys, e.g. `100.000000000049` and `100.000000000051`.
+/// - All values within epsilon of zero, including `-0.0`, are the key `0`, even
+///   `-epsilon` and `epsilon` which `==` tells apart.
+/// - Values above the decimal precision threshold are shown as-is, so their keys are
+///   the exact values.
+///
+/// A [`TolerantFloat`] with any other policy can be wrapped the same way, the keys
+/// only depend on the displayed value and never on the policy.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use extended_float::types::ExtendedFloat;
+/// use extended_float::types::extended_float::HashableExtendedFloat;
+///
+/// let mut prices = HashSet::new();
+/// prices.insert(HashableExtendedFloat::new(ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2)));
+/// prices.insert(HashableExtendedFloat::new(ExtendedFloat::new(0.3)));
+/// prices.insert(ExtendedFloat::new(0.31).into());
+///
+/// assert_eq!(prices.len(), 2);
+/// assert!(prices.contains(&ExtendedFloat::new(0.3).into()));
+/// ```
+pub struct HashableExtendedFloat<T: DisplayableFloat, Tol: Tolerance<T> = DefaultTolerance>(
+    TolerantFloat<T, Tol>,
+);
+
+impl_wrapper!(HashableExtendedFloat, "hashing");
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> PartialEq for HashableExtendedFloat<T, Tol> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_display(&other.0)
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Eq for HashableExtendedFloat<T, Tol> {}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Ord for HashableExtendedFloat<T, Tol> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_display(&other.0)
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> PartialOrd for HashableExtendedFloat<T, Tol> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Hash for HashableExtendedFloat<T, Tol> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.formatted().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::test_utils::{generator, hash_of};
+    use crate::types::ExtendedFloat;
+    use crate::types::extended_float::Absolute;
+
+    fn key(value: f64) -> HashableExtendedFloat<f64> {
+        ExtendedFloat::new(value).into()
+    }
+
+    #[test]
+    fn test_hashable_keys() {
+        let sum = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+        assert_eq!(HashableExtendedFloat::new(sum), key(0.3));
+        assert_eq!(
+            hash_of(&HashableExtendedFloat::new(sum)),
+            hash_of(&key(0.3))
+        );
+
+        assert_eq!(key(0.0), key(-0.0));
+        assert_eq!(key(1e-13), key(-1e-13));
+        assert_eq!(hash_of(&key(-0.0)), hash_of(&key(1e-13)));
+        assert_eq!(key(1e-12), key(-1e-12));
+        assert_ne!(key(1e20), key(1e20 + 65536.0));
+        assert_ne!(key(0.3), key(0.31));
+
+        // Equal under the tolerance, but shown differently
+        assert_eq!(
+            ExtendedFloat::new(100.000000000049),
+            ExtendedFloat::new(100.000000000051)
+        );
+        assert_ne!(key(100.000000000049), key(100.000000000051));
+
+        let f32_key = HashableExtendedFloat::new(ExtendedFloat::new(0.1_f32) * 3.0);
+        assert_eq!(f32_key, ExtendedFloat::new(0.3_f32).into());
+        assert_eq!(f32_key.get(), ExtendedFloat::new(0.3_f32));
+        assert_eq!(
+            format!("{} {:?}", key(0.5), key(0.5)),
+            "0.5 HashableExtendedFloat(0.5)"
+        );
+    }
+
+    #[test]
+    fn test_hashable_in_collections() {
+        let mut totals = HashMap::new();
+        for tick in 1..=1000 {
+            let price = ExtendedFloat::new(0.01) * f64::from(tick);
+            *totals.entry(HashableExtendedFloat::new(price)).or_insert(0) += 1;
+        }
+        assert_eq!(totals.len(), 1000);
+        assert_eq!(totals[&key(10.0)], 1);
+        assert_eq!(totals[&key(0.07)], 1);
+
+        let set: HashSet<_> = [0.1, 0.7 - 0.6, 0.2 - 0.1, 0.30000000000000004, 0.3]
+            .into_iter()
+            .map(key)
+            .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_hashable_equal_keys_hash_equally() {
+        let mut next = generator(0x9E37_79B9_7F4A_7C15);
+
+        for _ in 0..10_000 {
+            let magnitude = 10f64.powi((next() % 30) as i32 - 15);
+            let value = (next() % 2_000_001) as f64 / 1_000_000.0 * magnitude;
+            let ulps = (next() % 64) as i64 - 32;
+            let nearby = f64::from_bits((value.to_bits() as i64 + ulps).max(0) as u64);
+
+            let (a, b) = (key(value), key(nearby));
+            if a == b {
+                assert_eq!(hash_of(&a), hash_of(&b), "{} {}", value, nearby);
+                assert_eq!(a.get(), b.get(), "{} {}", value, nearby);
+            }
+            assert_eq!(a == b, a.get().format() == b.get().format());
+            assert_eq!(a == b, a.cmp(&b) == Ordering::Equal);
+        }
+    }
+
+    #[test]
+    fn test_hashable_with_tolerance() {
+        type Quantity = TolerantFloat<f64, Absolute<18>>;
+
+        // Told apart by the policy, but shown the same
+        let sum = Quantity::new(0.1) + Quantity::new(0.2);
+        assert_ne!(sum, Quantity::new(0.3));
+        let keys: HashSet<HashableExtendedFloat<f64, Absolute<18>>> =
+            [sum, Quantity::new(0.3), Quantity::new(1e-15)]
+                .into_iter()
+                .map(HashableExtendedFloat::new)
+                .collect();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.contains(&Quantity::new(0.3).into()));
+
+        let key = HashableExtendedFloat::new(Quantity::new(1e-15));
+        assert_eq!(key.get(), Quantity::new(1e-15));
+        assert_ne!(key.get(), Quantity::new(2e-15));
+    }
+}
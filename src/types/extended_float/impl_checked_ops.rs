@@ -1,7 +1,8 @@
-use crate::traits::{CheckedFloatOps, DisplayableFloat};
-use crate::types::extended_float::{ConversionError, ExtendedFloat};
+use super::TolerantFloat;
+use crate::traits::{CheckedFloatOps, DisplayableFloat, Tolerance};
+use crate::types::extended_float::ConversionError;
 
-impl<T: DisplayableFloat> CheckedFloatOps for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> CheckedFloatOps for TolerantFloat<T, Tol> {
     fn checked_add(&self, rhs: Self) -> Option<Self> {
         Self::try_new(self.downgrade() + rhs.downgrade())
     }
@@ -22,6 +23,10 @@ impl<T: DisplayableFloat> CheckedFloatOps for ExtendedFloat<T> {
         Self::try_new(self.downgrade() % rhs.downgrade())
     }
 
+    fn checked_mul_add(&self, a: Self, b: Self) -> Option<Self> {
+        Self::try_new(self.downgrade().mul_add(a.downgrade(), b.downgrade()))
+    }
+
     fn try_add(&self, rhs: Self) -> Result<Self, ConversionError> {
         Self::try_from_value(self.downgrade() + rhs.downgrade())
     }
@@ -41,11 +46,68 @@ impl<T: DisplayableFloat> CheckedFloatOps for ExtendedFloat<T> {
     fn try_rem(&self, rhs: Self) -> Result<Self, ConversionError> {
         Self::try_from_value(self.downgrade() % rhs.downgrade())
     }
+
+    fn try_mul_add(&self, a: Self, b: Self) -> Result<Self, ConversionError> {
+        Self::try_from_value(self.downgrade().mul_add(a.downgrade(), b.downgrade()))
+    }
+}
+
+/// Raw operands are validated like [`TolerantFloat::new`], NaN and infinite values
+/// give `None` or the matching [`ConversionError`].
+impl<T: DisplayableFloat, Tol: Tolerance<T>> CheckedFloatOps<T> for TolerantFloat<T, Tol> {
+    fn checked_add(&self, rhs: T) -> Option<Self> {
+        self.checked_add(Self::try_new(rhs)?)
+    }
+
+    fn checked_sub(&self, rhs: T) -> Option<Self> {
+        self.checked_sub(Self::try_new(rhs)?)
+    }
+
+    fn checked_mul(&self, rhs: T) -> Option<Self> {
+        self.checked_mul(Self::try_new(rhs)?)
+    }
+
+    fn checked_div(&self, rhs: T) -> Option<Self> {
+        self.checked_div(Self::try_new(rhs)?)
+    }
+
+    fn checked_rem(&self, rhs: T) -> Option<Self> {
+        self.checked_rem(Self::try_new(rhs)?)
+    }
+
+    fn checked_mul_add(&self, a: T, b: T) -> Option<Self> {
+        self.checked_mul_add(Self::try_new(a)?, Self::try_new(b)?)
+    }
+
+    fn try_add(&self, rhs: T) -> Result<Self, ConversionError> {
+        self.try_add(Self::try_from_value(rhs)?)
+    }
+
+    fn try_sub(&self, rhs: T) -> Result<Self, ConversionError> {
+        self.try_sub(Self::try_from_value(rhs)?)
+    }
+
+    fn try_mul(&self, rhs: T) -> Result<Self, ConversionError> {
+        self.try_mul(Self::try_from_value(rhs)?)
+    }
+
+    fn try_div(&self, rhs: T) -> Result<Self, ConversionError> {
+        self.try_div(Self::try_from_value(rhs)?)
+    }
+
+    fn try_rem(&self, rhs: T) -> Result<Self, ConversionError> {
+        self.try_rem(Self::try_from_value(rhs)?)
+    }
+
+    fn try_mul_add(&self, a: T, b: T) -> Result<Self, ConversionError> {
+        self.try_mul_add(Self::try_from_value(a)?, Self::try_from_value(b)?)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ExtendedFloat;
 
     #[test]
     fn test_checked_operations() {
@@ -93,4 +155,125 @@ mod tests {
             ConversionError::Infinite
         );
     }
+
+    #[test]
+    fn test_raw_operand_operations() {
+        let a = ExtendedFloat::new(10.0);
+
+        assert_eq!(a.checked_add(2.0).unwrap(), ExtendedFloat::new(12.0));
+        assert_eq!(a.checked_sub(2.0).unwrap(), ExtendedFloat::new(8.0));
+        assert_eq!(a.checked_mul(2.0).unwrap(), ExtendedFloat::new(20.0));
+        assert_eq!(a.checked_div(2.0).unwrap(), ExtendedFloat::new(5.0));
+        assert_eq!(a.checked_rem(3.0).unwrap(), ExtendedFloat::new(1.0));
+        assert_eq!(a.try_add(2.0).unwrap(), ExtendedFloat::new(12.0));
+        assert_eq!(a.try_div(4.0).unwrap(), ExtendedFloat::new(2.5));
+
+        // Results are checked like for ExtendedFloat operands
+        assert!(a.checked_div(0.0).is_none());
+        assert_eq!(a.try_div(0.0).unwrap_err(), ConversionError::Infinite);
+        assert_eq!(a.try_rem(0.0).unwrap_err(), ConversionError::NaN);
+
+        // Raw operands are validated like new
+        assert!(a.checked_add(f64::NAN).is_none());
+        assert!(a.checked_mul(f64::INFINITY).is_none());
+        assert_eq!(a.try_sub(f64::NAN).unwrap_err(), ConversionError::NaN);
+        assert_eq!(
+            a.try_mul(f64::NEG_INFINITY).unwrap_err(),
+            ConversionError::Infinite
+        );
+        // Even when the result would be finite
+        assert_eq!(
+            a.try_div(f64::INFINITY).unwrap_err(),
+            ConversionError::Infinite
+        );
+    }
+
+    #[test]
+    fn test_mul_add_operations() {
+        let a = ExtendedFloat::new(0.1);
+
+        assert_eq!(
+            a.checked_mul_add(ExtendedFloat::new(3.0), ExtendedFloat::new(0.7))
+                .unwrap(),
+            ExtendedFloat::new(1.0)
+        );
+        assert_eq!(a.try_mul_add(10.0, -1.0).unwrap(), ExtendedFloat::new(0.0));
+
+        // Single rounding: the product's rounding error survives the cancellation
+        let exact_error = 0.1_f64.mul_add(10.0, -1.0);
+        assert_ne!(exact_error, 0.0);
+        assert_eq!(a.try_mul_add(10.0, -1.0).unwrap().downgrade(), exact_error);
+
+        let huge = ExtendedFloat::new(f64::MAX);
+        assert!(huge.checked_mul_add(huge, huge).is_none());
+        assert_eq!(
+            huge.try_mul_add(2.0, 0.0).unwrap_err(),
+            ConversionError::Infinite
+        );
+        assert_eq!(
+            a.try_mul_add(f64::NAN, 1.0).unwrap_err(),
+            ConversionError::NaN
+        );
+        assert!(a.checked_mul_add(1.0, f64::INFINITY).is_none());
+    }
+
+    /// Implementor relying on the default `mul_add` methods
+    #[derive(Debug, PartialEq)]
+    struct Units(i64);
+
+    impl CheckedFloatOps for Units {
+        fn checked_add(&self, rhs: Self) -> Option<Self> {
+            self.0.checked_add(rhs.0).map(Units)
+        }
+
+        fn checked_sub(&self, rhs: Self) -> Option<Self> {
+            self.0.checked_sub(rhs.0).map(Units)
+        }
+
+        fn checked_mul(&self, rhs: Self) -> Option<Self> {
+            self.0.checked_mul(rhs.0).map(Units)
+        }
+
+        fn checked_div(&self, rhs: Self) -> Option<Self> {
+            self.0.checked_div(rhs.0).map(Units)
+        }
+
+        fn checked_rem(&self, rhs: Self) -> Option<Self> {
+            self.0.checked_rem(rhs.0).map(Units)
+        }
+
+        fn try_add(&self, rhs: Self) -> Result<Self, ConversionError> {
+            self.checked_add(rhs).ok_or(ConversionError::Infinite)
+        }
+
+        fn try_sub(&self, rhs: Self) -> Result<Self, ConversionError> {
+            self.checked_sub(rhs).ok_or(ConversionError::Infinite)
+        }
+
+        fn try_mul(&self, rhs: Self) -> Result<Self, ConversionError> {
+            self.checked_mul(rhs).ok_or(ConversionError::Infinite)
+        }
+
+        fn try_div(&self, rhs: Self) -> Result<Self, ConversionError> {
+            self.checked_div(rhs).ok_or(ConversionError::NaN)
+        }
+
+        fn try_rem(&self, rhs: Self) -> Result<Self, ConversionError> {
+            self.checked_rem(rhs).ok_or(ConversionError::NaN)
+        }
+    }
+
+    #[test]
+    fn test_default_mul_add() {
+        assert_eq!(
+            Units(3).checked_mul_add(Units(4), Units(5)),
+            Some(Units(17))
+        );
+        assert_eq!(Units(3).try_mul_add(Units(4), Units(-5)), Ok(Units(7)));
+        assert_eq!(Units(i64::MAX).checked_mul_add(Units(2), Units(-1)), None);
+        assert_eq!(
+            Units(i64::MAX).try_mul_add(Units(1), Units(1)),
+            Err(ConversionError::Infinite)
+        );
+    }
 }
@@ -1,8 +1,8 @@
 use std::convert::From;
 use std::fmt;
 
-use super::ExtendedFloat;
-use crate::traits::DisplayableFloat;
+use super::TolerantFloat;
+use crate::traits::{DisplayableFloat, Tolerance};
 use crate::utils::check_invalid_float;
 
 /// Error type for conversion failures when creating ExtendedFloat
@@ -27,14 +27,101 @@ impl fmt::Display for ConversionError {
 
 impl std::error::Error for ConversionError {}
 
-impl<T: DisplayableFloat> From<T> for ExtendedFloat<T> {
+impl ConversionError {
+    /// Panics with the message [`ExtendedFloat::new`] uses for the invalid value
+    #[cold]
+    #[track_caller]
+    pub(crate) fn panic(self) -> ! {
+        match self {
+            ConversionError::NaN => panic!("ExtendedFloat doesn't support NaN values"),
+            ConversionError::Infinite => panic!("ExtendedFloat doesn't support infinite values"),
+        }
+    }
+}
+
+/// Kind of failure reported by [`ParseError`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Input has no digits to parse
+    Empty,
+    /// Input contains a character that can't appear at its position
+    InvalidDigit,
+    /// Value is too large in magnitude to be represented by the float type
+    ExponentOverflow,
+    /// Input spells out NaN or infinity
+    NonFinite,
+}
+
+/// Error type for parsing failures when creating ExtendedFloat from text
+///
+/// Besides the [`ParseErrorKind`], it reports the byte position in the input
+/// at which the failure was detected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    position: usize,
+}
+
+impl ParseError {
+    #[inline]
+    pub(crate) fn new(kind: ParseErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    /// Returns the kind of the failure
+    #[inline]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte position in the input at which the failure was detected
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Empty => {
+                write!(
+                    f,
+                    "Cannot parse ExtendedFloat without digits at position {}",
+                    self.position
+                )
+            }
+            ParseErrorKind::InvalidDigit => {
+                write!(f, "Invalid digit at position {}", self.position)
+            }
+            ParseErrorKind::ExponentOverflow => {
+                write!(
+                    f,
+                    "Exponent overflow at position {}, value is out of range",
+                    self.position
+                )
+            }
+            ParseErrorKind::NonFinite => {
+                write!(
+                    f,
+                    "Cannot create ExtendedFloat from non-finite value at position {}",
+                    self.position
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> From<T> for TolerantFloat<T, Tol> {
     #[inline]
     fn from(value: T) -> Self {
         Self::new(value)
     }
 }
 
-impl<T: DisplayableFloat> ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
     /// Try to create ExtendedFloat safely without panicking
     ///
     /// Unlike the `new()` constructor, this method returns a Result with error details
@@ -53,15 +140,15 @@ impl<T: DisplayableFloat> ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> From<ExtendedFloat<T>> for String {
-    fn from(value: ExtendedFloat<T>) -> Self {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> From<TolerantFloat<T, Tol>> for String {
+    fn from(value: TolerantFloat<T, Tol>) -> Self {
         value.to_string()
     }
 }
 
 // TODO: bench
-impl<T: DisplayableFloat + Into<f64>> From<ExtendedFloat<T>> for f64 {
-    fn from(value: ExtendedFloat<T>) -> Self {
+impl<T: DisplayableFloat + Into<f64>, Tol: Tolerance<T>> From<TolerantFloat<T, Tol>> for f64 {
+    fn from(value: TolerantFloat<T, Tol>) -> Self {
         value.downgrade().into()
     }
 }
@@ -71,6 +158,7 @@ mod tests {
     use std::f64::consts::PI;
 
     use super::*;
+    use crate::types::ExtendedFloat;
 
     #[test]
     fn test_try_from_value() {
@@ -1,10 +1,370 @@
 use std::fmt;
+use std::fmt::Write;
 
-use super::ExtendedFloat;
-use crate::traits::DisplayableFloat;
+use super::decimal::Decimal;
+use super::formatted::SliceWriter;
+use super::{RoundingMode, TolerantFloat};
+use crate::traits::{DisplayableFloat, Float, Tolerance};
 
-impl<T: DisplayableFloat> fmt::Display for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> fmt::Display for TolerantFloat<T, Tol> {
+    /// Formats the value using [`TolerantFloat::format`] while honoring the formatter flags.
+    ///
+    /// Width, fill, alignment, `+` and `0` flags behave as for primitive floats.
+    /// An explicit precision (`{:.2}`) replaces the dynamically calculated one,
+    /// but the value is still rounded at its meaningful precision first, so
+    /// floating-point artifacts can't leak into the requested digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+    /// assert_eq!(format!("{}", value), "0.3");
+    /// assert_eq!(format!("{:>6}", value), "   0.3");
+    /// assert_eq!(format!("{:+}", value), "+0.3");
+    /// assert_eq!(format!("{:.3}", value), "0.300");
+    /// assert_eq!(format!("{:08.2}", -value), "-0000.30");
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.format())
+        match f.precision() {
+            Some(precision) => pad_written(f, |w| self.write_with_precision(w, precision)),
+            None => pad_signed(f, &self.formatted()),
+        }
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> fmt::LowerExp for TolerantFloat<T, Tol> {
+    /// Formats the value in scientific notation, e.g. `3.2e-14`.
+    ///
+    /// Unlike [`Display`](fmt::Display), values within epsilon are not collapsed to zero
+    /// and big values don't expand into all their digits. The mantissa is rounded at the
+    /// significant decimal digits of the type, so floating-point artifacts are removed.
+    /// Formatter flags behave as for primitive floats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+    /// assert_eq!(format!("{:e}", value), "3e-1");
+    /// assert_eq!(format!("{:e}", ExtendedFloat::new(3.2e-14)), "3.2e-14");
+    /// assert_eq!(format!("{:.2e}", ExtendedFloat::new(1234.5)), "1.23e3");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        pad_written(f, |w| self.write_exp(w, 1, precision, false))
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> fmt::UpperExp for TolerantFloat<T, Tol> {
+    /// Formats the value like [`LowerExp`](fmt::LowerExp) with an upper case `E`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        pad_written(f, |w| self.write_exp(w, 1, precision, true))
+    }
+}
+
+/// Stack space for values written with formatter flags
+const STACK_BUFFER_LEN: usize = 64;
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
+    /// Formats the value in engineering notation, with the exponent a multiple of 3.
+    ///
+    /// The mantissa is rounded like for [`LowerExp`](fmt::LowerExp).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// assert_eq!(ExtendedFloat::new(12345.0).format_engineering(), "12.345e3");
+    /// assert_eq!(ExtendedFloat::new(0.00032).format_engineering(), "320e-6");
+    /// ```
+    pub fn format_engineering(&self) -> String {
+        let mut formatted = String::with_capacity(32);
+        self.write_exp(&mut formatted, 3, None, false).unwrap();
+        formatted
+    }
+
+    /// Formats the value like [`format`](Self::format), switching to scientific
+    /// notation for magnitudes it can't show meaningfully.
+    ///
+    /// Non-zero values within epsilon, which [`format`](Self::format) prints as `"0"`,
+    /// and values above the decimal precision threshold, which it prints with all their
+    /// digits, are formatted like [`LowerExp`](fmt::LowerExp).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// assert_eq!(ExtendedFloat::new(3.2e-14).format_auto(), "3.2e-14");
+    /// assert_eq!(ExtendedFloat::new(1e300).format_auto(), "1e300");
+    /// assert_eq!(ExtendedFloat::new(0.25).format_auto(), "0.25");
+    /// assert_eq!(ExtendedFloat::new(0.0).format_auto(), "0");
+    /// ```
+    pub fn format_auto(&self) -> String {
+        let value = self.downgrade().abs();
+        let is_tiny = value <= <T as Float>::epsilon() && !value.is_zero();
+        if is_tiny || value > T::decimal_precision() {
+            let mut formatted = String::with_capacity(32);
+            self.write_exp(&mut formatted, 1, None, false).unwrap();
+            return formatted;
+        }
+
+        self.format()
+    }
+
+    /// Writes the value in exponent notation with the exponent a multiple of
+    /// `exponent_step` and, if set, exactly `precision` fractional mantissa digits.
+    ///
+    /// There is no magnitude limit in exponent notation, so the mantissa is rounded at
+    /// the significant decimal digits of the type rather than at the dynamic precision.
+    fn write_exp<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        exponent_step: i32,
+        precision: Option<usize>,
+        upper: bool,
+    ) -> fmt::Result {
+        let value = self.downgrade();
+
+        // Non-finite values are written the same way in every notation
+        if !value.is_finite() {
+            return write!(w, "{}", value);
+        }
+
+        let mut decimal = Decimal::from_shortest(value);
+        decimal.round_significant(
+            T::decimal_precision_digits() as usize,
+            RoundingMode::HalfEven,
+        );
+        if let Some(precision) = precision {
+            decimal.round_significant(precision + 1, RoundingMode::HalfEven);
+        }
+        decimal.write_exp(w, exponent_step, precision.unwrap_or(0), upper)
+    }
+
+    /// Writes the value with exactly `precision` fractional digits.
+    ///
+    /// The value is rounded at its dynamic precision first and only then at the
+    /// requested one (half to even, like primitive floats), so e.g. `2.675`, stored
+    /// as `2.67499999999999982236431605997495353221893310546875`, becomes `2.68`.
+    fn write_with_precision<W: Write + ?Sized>(&self, w: &mut W, precision: usize) -> fmt::Result {
+        let value = self.downgrade();
+
+        // Values within epsilon are zero, including their sign
+        if value.abs() <= <T as Float>::epsilon() {
+            return Decimal::ZERO.write_fixed(w, precision);
+        }
+
+        // Too big or not finite, there is no meaningful precision to round at
+        if !value.is_finite() || value.abs() > T::decimal_precision() {
+            return write!(w, "{:.*}", precision, value);
+        }
+
+        // A value that rounds to zero is written without a sign
+        let mut decimal = Decimal::from_float(value, value.precision());
+        decimal.round_fraction(precision, RoundingMode::HalfEven);
+        decimal.write_fixed(w, precision)
+    }
+}
+
+/// Pads the output of `write` like [`pad_signed`], on the stack unless it's too long
+fn pad_written<F>(f: &mut fmt::Formatter<'_>, write: F) -> fmt::Result
+where
+    F: Fn(&mut dyn Write) -> fmt::Result,
+{
+    let mut buffer = [0u8; STACK_BUFFER_LEN];
+    let mut writer = SliceWriter::new(&mut buffer);
+    if write(&mut writer).is_ok() {
+        return pad_signed(f, writer.as_str());
+    }
+
+    // Only very big values or precisions don't fit and need to allocate
+    let mut formatted = String::new();
+    write(&mut formatted)?;
+    pad_signed(f, &formatted)
+}
+
+/// Writes a formatted number taking care of width, fill, alignment, explicit sign
+/// and sign-aware zero padding
+fn pad_signed(f: &mut fmt::Formatter<'_>, formatted: &str) -> fmt::Result {
+    let (is_nonnegative, digits) = match formatted.strip_prefix('-') {
+        Some(digits) => (false, digits),
+        None => (true, formatted),
+    };
+
+    f.pad_integral(is_nonnegative, "", digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::types::ExtendedFloat;
+
+    #[test]
+    fn test_display_default() {
+        let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+        assert_eq!(format!("{}", value), "0.3");
+        assert_eq!(format!("{}", value), value.format());
+        assert_eq!(format!("{}", ExtendedFloat::new(-1234.5)), "-1234.5");
+    }
+
+    #[test]
+    fn test_display_width_and_alignment() {
+        let value = ExtendedFloat::new(12.5);
+        assert_eq!(format!("{:8}", value), "    12.5");
+        assert_eq!(format!("{:>8}", value), "    12.5");
+        assert_eq!(format!("{:<8}|", value), "12.5    |");
+        assert_eq!(format!("{:^8}", value), "  12.5  ");
+        assert_eq!(format!("{:*>8}", value), "****12.5");
+        assert_eq!(format!("{:>8}", -value), "   -12.5");
+        assert_eq!(format!("{:2}", value), "12.5");
+    }
+
+    #[test]
+    fn test_display_sign_and_zero_padding() {
+        let value = ExtendedFloat::new(12.5);
+        assert_eq!(format!("{:+}", value), "+12.5");
+        assert_eq!(format!("{:+}", -value), "-12.5");
+        assert_eq!(format!("{:08}", value), "000012.5");
+        assert_eq!(format!("{:08}", -value), "-00012.5");
+        assert_eq!(format!("{:+08}", value), "+00012.5");
+
+        // Zero is never negative
+        assert_eq!(format!("{:+}", ExtendedFloat::new(-1e-13)), "+0");
+    }
+
+    #[test]
+    fn test_display_precision() {
+        assert_eq!(format!("{:.2}", ExtendedFloat::new(1.5)), "1.50");
+        assert_eq!(format!("{:.0}", ExtendedFloat::new(1.4)), "1");
+        assert_eq!(format!("{:.3}", ExtendedFloat::new(7.0)), "7.000");
+        assert_eq!(format!("{:.2}", ExtendedFloat::new(0.0)), "0.00");
+        assert_eq!(format!("{:.2}", ExtendedFloat::new(1e-13)), "0.00");
+        assert_eq!(format!("{:.2}", ExtendedFloat::new(-0.001)), "0.00");
+        assert_eq!(format!("{:.1}", ExtendedFloat::new(9.96)), "10.0");
+        assert_eq!(format!("{:.0}", ExtendedFloat::new(-99.5)), "-100");
+        assert_eq!(
+            format!("{:10.3}", ExtendedFloat::new(-1.23456)),
+            "    -1.235"
+        );
+        assert_eq!(format!("{:+.1}", ExtendedFloat::new(0.25)), "+0.2");
+    }
+
+    #[test]
+    fn test_display_precision_smart_rounding() {
+        // Artifacts are removed before the requested precision is applied
+        let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+        assert_eq!(format!("{:.20}", value), "0.30000000000000000000");
+        assert_eq!(
+            format!("{:.20}", value.downgrade()),
+            "0.30000000000000004441"
+        );
+
+        // 2.675 is stored slightly below the midpoint
+        assert_eq!(format!("{:.2}", 2.675), "2.67");
+        assert_eq!(format!("{:.2}", ExtendedFloat::new(2.675)), "2.68");
+
+        // Ties go to even, like for primitive floats
+        assert_eq!(format!("{:.2}", ExtendedFloat::new(2.665)), "2.66");
+        assert_eq!(format!("{:.1}", ExtendedFloat::new(0.35)), "0.4");
+        assert_eq!(format!("{:.0}", ExtendedFloat::new(2.5)), "2");
+        assert_eq!(format!("{:.0}", ExtendedFloat::new(3.5)), "4");
+
+        // Big values are formatted as-is
+        assert_eq!(
+            format!("{:.2}", ExtendedFloat::new(1e20)),
+            format!("{:.2}", 1e20)
+        );
+    }
+
+    #[test]
+    fn test_display_f32() {
+        let value = ExtendedFloat::new(0.1_f32) + ExtendedFloat::new(0.2_f32);
+        assert_eq!(format!("{:>6}", value), "   0.3");
+        assert_eq!(format!("{:.8}", value), "0.30000000");
+        assert_eq!(format!("{:+.1}", ExtendedFloat::new(-1.25_f32)), "-1.2");
+    }
+
+    #[test]
+    fn test_lower_exp() {
+        let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+        assert_eq!(format!("{:e}", value), "3e-1");
+        assert_eq!(format!("{:e}", ExtendedFloat::new(0.0)), "0e0");
+        assert_eq!(format!("{:e}", ExtendedFloat::new(-0.0)), "0e0");
+        assert_eq!(format!("{:e}", ExtendedFloat::new(1.5)), "1.5e0");
+        assert_eq!(format!("{:e}", ExtendedFloat::new(-1234.5)), "-1.2345e3");
+        assert_eq!(format!("{:e}", ExtendedFloat::new(3.2e-14)), "3.2e-14");
+        assert_eq!(format!("{:e}", ExtendedFloat::new(1e300)), "1e300");
+        assert_eq!(
+            format!("{:e}", ExtendedFloat::new(f64::MAX)),
+            "1.79769313486232e308"
+        );
+    }
+
+    #[test]
+    fn test_exp_flags() {
+        let value = ExtendedFloat::new(1234.5);
+        assert_eq!(format!("{:E}", value), "1.2345E3");
+        assert_eq!(format!("{:.2e}", value), "1.23e3");
+        assert_eq!(format!("{:.6e}", value), "1.234500e3");
+        assert_eq!(format!("{:.0e}", ExtendedFloat::new(9.6)), "1e1");
+        assert_eq!(format!("{:+e}", value), "+1.2345e3");
+        assert_eq!(format!("{:>10e}", -value), " -1.2345e3");
+        assert_eq!(format!("{:010e}", -value), "-01.2345e3");
+
+        // Artifacts are removed before the requested precision is applied
+        let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+        assert_eq!(format!("{:.20e}", value), "3.00000000000000000000e-1");
+    }
+
+    #[test]
+    fn test_exp_f32() {
+        let value = ExtendedFloat::new(0.1_f32) + ExtendedFloat::new(0.2_f32);
+        assert_eq!(format!("{:e}", value), "3e-1");
+        assert_eq!(format!("{:e}", ExtendedFloat::new(3.2e-8_f32)), "3.2e-8");
+        assert_eq!(format!("{:e}", ExtendedFloat::new(f32::MAX)), "3.40282e38");
+    }
+
+    #[test]
+    fn test_format_engineering() {
+        assert_eq!(ExtendedFloat::new(0.0).format_engineering(), "0e0");
+        assert_eq!(ExtendedFloat::new(1.5).format_engineering(), "1.5e0");
+        assert_eq!(ExtendedFloat::new(12345.0).format_engineering(), "12.345e3");
+        assert_eq!(
+            ExtendedFloat::new(-123456.0).format_engineering(),
+            "-123.456e3"
+        );
+        assert_eq!(ExtendedFloat::new(0.00032).format_engineering(), "320e-6");
+        assert_eq!(ExtendedFloat::new(3.2e-14).format_engineering(), "32e-15");
+        assert_eq!(ExtendedFloat::new(1e300).format_engineering(), "1e300");
+        assert_eq!(ExtendedFloat::new(1e301).format_engineering(), "10e300");
+    }
+
+    #[test]
+    fn test_format_auto() {
+        assert_eq!(ExtendedFloat::new(0.0).format_auto(), "0");
+        assert_eq!(ExtendedFloat::new(3.2e-14).format_auto(), "3.2e-14");
+        assert_eq!(ExtendedFloat::new(-3.2e-14).format_auto(), "-3.2e-14");
+        assert_eq!(ExtendedFloat::new(1e-12).format_auto(), "1e-12");
+        assert_eq!(ExtendedFloat::new(2e-12).format_auto(), "0.000000000002");
+        assert_eq!(ExtendedFloat::new(1e15).format_auto(), "1000000000000000");
+        assert_eq!(ExtendedFloat::new(1.5e15).format_auto(), "1.5e15");
+        assert_eq!(
+            ExtendedFloat::new(f64::MIN).format_auto(),
+            "-1.79769313486232e308"
+        );
+
+        let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+        assert_eq!(value.format_auto(), value.format());
+
+        assert_eq!(ExtendedFloat::new(3.2e-8_f32).format_auto(), "3.2e-8");
+        assert_eq!(ExtendedFloat::new(2.5e7_f32).format_auto(), "2.5e7");
+        assert_eq!(ExtendedFloat::new(1.25_f32).format_auto(), "1.25");
     }
 }
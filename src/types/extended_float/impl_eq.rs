@@ -1,9 +1,9 @@
 use std::cmp::Eq;
 
-use super::ExtendedFloat;
-use crate::traits::{DisplayableFloat, Float};
+use super::TolerantFloat;
+use crate::traits::{DisplayableFloat, Tolerance};
 
-impl<T: DisplayableFloat> PartialEq for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> PartialEq for TolerantFloat<T, Tol> {
     fn eq(&self, other: &Self) -> bool {
         if self.downgrade() == other.downgrade() {
             return true;
@@ -13,23 +13,41 @@ impl<T: DisplayableFloat> PartialEq for ExtendedFloat<T> {
             return false;
         }
 
-        let abs_diff = (self.downgrade() - other.downgrade()).abs();
-        let epsilon = <T as Float>::epsilon();
+        Tol::approx_eq(self.downgrade(), other.downgrade())
+    }
+}
 
-        if abs_diff <= epsilon {
-            return true;
-        }
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Eq for TolerantFloat<T, Tol> {}
 
-        abs_diff < epsilon * self.downgrade().abs().min(other.downgrade().abs())
+impl<T: DisplayableFloat, Tol: Tolerance<T>> PartialEq<T> for TolerantFloat<T, Tol> {
+    /// Compares with a raw value using the same tolerance.
+    ///
+    /// The raw value is validated like [`TolerantFloat::new`], but instead of
+    /// panicking, NaN and infinite values are never equal.
+    fn eq(&self, other: &T) -> bool {
+        TolerantFloat::try_new(*other).is_some_and(|other| *self == other)
     }
 }
 
-impl<T: DisplayableFloat> Eq for ExtendedFloat<T> {}
+/// Implements comparison with the raw float on the left-hand side, which the
+/// orphan rule only allows for concrete types
+macro_rules! impl_raw_lhs_eq {
+    ($($float:ty),*) => {$(
+        impl<Tol: Tolerance<$float>> PartialEq<TolerantFloat<$float, Tol>> for $float {
+            fn eq(&self, other: &TolerantFloat<$float, Tol>) -> bool {
+                other == self
+            }
+        }
+    )*};
+}
+
+impl_raw_lhs_eq!(f32, f64);
 
 #[cfg(test)]
 mod tests {
     use std::rc::Rc;
 
+    use crate::constants::f32::EPSILON as EPSILON_F32;
     use crate::constants::f64::EPSILON as EPSILON_F64;
     use crate::types::ExtendedFloat;
 
@@ -57,6 +75,68 @@ mod tests {
         assert_eq!(count, total);
     }
 
+    fn calculate_f32(start: f32, decrement: f32, count: i32) -> ExtendedFloat<f32> {
+        let mut start = start;
+        for _ in 0..count {
+            start -= decrement;
+        }
+        ExtendedFloat::new(start)
+    }
+
+    #[test]
+    fn test_equal_f32() {
+        let total = 1_000;
+        let mut count = 0;
+        for i in 0..total {
+            let expected = (i as f32) / 10.0;
+            let actual = calculate_f32(1.0 + expected, 0.2, 5);
+            if actual == ExtendedFloat::new(expected) {
+                count += 1;
+            }
+        }
+        assert_eq!(count, total);
+    }
+
+    #[test]
+    fn test_equal_table_f32() {
+        let cases = [
+            (0.0000001_f32, 0.0000002_f32, true),
+            (0.000001, 0.000002, true),
+            (0.00001, 0.00002, false),
+            (0.1, 0.2, false),
+            (0.1 + 0.2, 0.3, true),
+            (0.0, 1.0, false),
+            (100.0, 101.0, false),
+            (1_000.0, 1_000.01, false),
+            (1_000_000.0, 1_000_001.0, false),
+            (10_000_000.0, 10_000_001.0, true),
+        ];
+
+        for (a, b, equal) in cases {
+            assert_eq!(
+                ExtendedFloat::new(a) == ExtendedFloat::new(b),
+                equal,
+                "Failed for {} and {}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn test_zero_equality_f32() {
+        assert_eq!(ExtendedFloat::new(0.0_f32), ExtendedFloat::new(-0.0_f32));
+        assert_eq!(
+            ExtendedFloat::new(0.0_f32),
+            ExtendedFloat::new(EPSILON_F32 * 0.5)
+        );
+        assert_eq!(ExtendedFloat::new(0.0_f32), ExtendedFloat::new(EPSILON_F32));
+        assert_ne!(
+            ExtendedFloat::new(0.0_f32),
+            ExtendedFloat::new(EPSILON_F32 * 10.0)
+        );
+    }
+
     #[test]
     fn test_equal_table_f64() {
         struct TestCase {
@@ -470,6 +550,21 @@ mod tests {
         assert_ne!(ref1, ref_none);
     }
 
+    #[test]
+    fn test_raw_equality() {
+        let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+        assert_eq!(value, 0.3);
+        assert_eq!(0.3, value);
+        assert_ne!(value, 0.4);
+        assert_ne!(0.4, value);
+        assert_eq!(ExtendedFloat::new(1.0_f32), 1.0 + EPSILON_F32 * 0.5);
+
+        // Invalid raw values are never equal
+        assert_ne!(ExtendedFloat::new(0.0), f64::NAN);
+        assert_ne!(ExtendedFloat::new(f64::MAX), f64::INFINITY);
+        assert_ne!(f64::NAN, ExtendedFloat::new(0.0));
+    }
+
     #[test]
     fn test_smart_pointers_equality() {
         let opt1 = Some(ExtendedFloat::new(1.0));
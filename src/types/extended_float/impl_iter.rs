@@ -0,0 +1,421 @@
+use std::borrow::Borrow;
+use std::iter::{Product, Sum};
+
+use super::{ConversionError, ExtendedFloat, TolerantFloat};
+use crate::traits::{DisplayableFloat, Tolerance};
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
+    /// Sums the values with Neumaier compensated summation.
+    ///
+    /// The rounding error of every addition is tracked and added back at the end, so
+    /// long sums don't pick up the drift of a naive fold. Fails as soon as a partial
+    /// sum is NaN or infinite, like chained `+` would panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    /// use extended_float::types::extended_float::ConversionError;
+    ///
+    /// let values = vec![ExtendedFloat::new(0.1); 10];
+    /// assert_eq!(ExtendedFloat::try_sum(&values).unwrap().downgrade(), 1.0);
+    ///
+    /// let values = [ExtendedFloat::new(f64::MAX), ExtendedFloat::new(f64::MAX)];
+    /// assert_eq!(ExtendedFloat::try_sum(values), Err(ConversionError::Infinite));
+    /// ```
+    pub fn try_sum<I>(values: I) -> Result<Self, ConversionError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        let mut sum = CompensatedSum::new();
+        for value in values {
+            sum.add(value.borrow().downgrade())?;
+        }
+        sum.value()
+    }
+
+    /// Multiplies the values, compensating the rounding error of every multiplication.
+    ///
+    /// The error of each product is recovered exactly with a fused multiply-add and
+    /// carried along. Fails as soon as a partial product is NaN or infinite, like
+    /// chained `*` would panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let values = [ExtendedFloat::new(1.1), ExtendedFloat::new(1.1), ExtendedFloat::new(1.1)];
+    /// assert_eq!(ExtendedFloat::try_product(&values).unwrap().format(), "1.331");
+    /// ```
+    pub fn try_product<I>(values: I) -> Result<Self, ConversionError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        let mut product = CompensatedProduct::new();
+        for value in values {
+            product.mul(value.borrow().downgrade())?;
+        }
+        product.value()
+    }
+
+    /// Computes the dot product `a[0] * b[0] + a[1] * b[1] + ...` with compensation.
+    ///
+    /// The rounding error of every product is recovered exactly with a fused
+    /// multiply-add, and the products and their errors are summed like
+    /// [`try_sum`](Self::try_sum) does, so weighted sums are as accurate as if they
+    /// were computed with twice the precision.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if:
+    /// - The slices have different lengths
+    /// - A product or a partial sum would be infinite
+    ///
+    /// For a non-panicking version, use `try_dot`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let weights = [0.1, 0.2, 0.3, 0.4].map(ExtendedFloat::new);
+    /// let prices = [101.3, 99.7, 100.1, 100.4].map(ExtendedFloat::new);
+    /// assert_eq!(ExtendedFloat::dot(&weights, &prices).format(), "100.26");
+    /// ```
+    pub fn dot(a: &[Self], b: &[Self]) -> Self {
+        Self::try_dot(a, b).unwrap_or_else(|error| error.panic())
+    }
+
+    /// Similar to [`dot`](Self::dot) but returns a Result with detailed error information
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the slices have different lengths. That's a bug in the
+    /// caller rather than a property of the values, so it isn't a [`ConversionError`].
+    pub fn try_dot(a: &[Self], b: &[Self]) -> Result<Self, ConversionError> {
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "Dot product of slices with different lengths"
+        );
+
+        let mut sum = CompensatedSum::new();
+        for (a, b) in a.iter().zip(b) {
+            let (a, b) = (a.downgrade(), b.downgrade());
+            let product = Self::try_from_value(a * b)?.downgrade();
+            sum.add(product)?;
+            // The exact rounding error of the product
+            sum.add(a.mul_add(b, -product))?;
+        }
+        sum.value()
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Sum for TolerantFloat<T, Tol> {
+    /// Sums the values with compensated summation, see [`ExtendedFloat::try_sum`].
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if a partial sum would be NaN or infinite.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::try_sum(iter).unwrap_or_else(|error| error.panic())
+    }
+}
+
+impl<'a, T: DisplayableFloat, Tol: Tolerance<T>> Sum<&'a TolerantFloat<T, Tol>>
+    for TolerantFloat<T, Tol>
+{
+    /// Sums the values with compensated summation, see [`ExtendedFloat::try_sum`].
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if a partial sum would be NaN or infinite.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self::try_sum(iter).unwrap_or_else(|error| error.panic())
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Product for TolerantFloat<T, Tol> {
+    /// Multiplies the values with compensation, see [`ExtendedFloat::try_product`].
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if a partial product would be NaN or infinite.
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::try_product(iter).unwrap_or_else(|error| error.panic())
+    }
+}
+
+impl<'a, T: DisplayableFloat, Tol: Tolerance<T>> Product<&'a TolerantFloat<T, Tol>>
+    for TolerantFloat<T, Tol>
+{
+    /// Multiplies the values with compensation, see [`ExtendedFloat::try_product`].
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if a partial product would be NaN or infinite.
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self::try_product(iter).unwrap_or_else(|error| error.panic())
+    }
+}
+
+/// Running sum with second-order Neumaier compensation.
+///
+/// The rounding errors of the additions are summed with compensation too, otherwise
+/// the error sum itself drifts over long `f32` series.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CompensatedSum<T> {
+    sum: T,
+    /// Rounding errors of the additions to `sum`
+    compensation: T,
+    /// Rounding errors of the additions to `compensation`
+    second_compensation: T,
+}
+
+impl<T: DisplayableFloat> CompensatedSum<T> {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {
+            sum: T::zero(),
+            compensation: T::zero(),
+            second_compensation: T::zero(),
+        }
+    }
+
+    /// Adds a finite value, failing if the partial sum isn't finite
+    #[inline]
+    pub(crate) fn add(&mut self, value: T) -> Result<(), ConversionError> {
+        ExtendedFloat::try_from_value(self.sum + value)?;
+
+        let (sum, error) = two_sum(self.sum, value);
+        let (compensation, second_error) = two_sum(self.compensation, error);
+        self.sum = sum;
+        self.compensation = compensation;
+        self.second_compensation = self.second_compensation + second_error;
+        Ok(())
+    }
+
+    #[inline]
+    pub(crate) fn value<Tol: Tolerance<T>>(
+        &self,
+    ) -> Result<TolerantFloat<T, Tol>, ConversionError> {
+        TolerantFloat::try_from_value(self.sum + (self.compensation + self.second_compensation))
+    }
+}
+
+/// Returns the rounded sum and its exact rounding error
+#[inline]
+fn two_sum<T: DisplayableFloat>(a: T, b: T) -> (T, T) {
+    let sum = a + b;
+    // The smaller operand loses its low bits, recover them exactly
+    let error = match a.abs() >= b.abs() {
+        true => (a - sum) + b,
+        false => (b - sum) + a,
+    };
+    (sum, error)
+}
+
+/// Running product that carries the rounding error of every multiplication
+#[derive(Debug, Clone, Copy)]
+struct CompensatedProduct<T> {
+    product: T,
+    /// Rounding errors of the multiplications so far, scaled by the later factors, an
+    /// absolute amount that `product` is off from the exact product
+    compensation: T,
+}
+
+impl<T: DisplayableFloat> CompensatedProduct<T> {
+    #[inline]
+    fn new() -> Self {
+        Self {
+            product: T::one(),
+            compensation: T::zero(),
+        }
+    }
+
+    /// Multiplies by a finite value, failing if the partial product isn't finite
+    #[inline]
+    fn mul(&mut self, value: T) -> Result<(), ConversionError> {
+        let product = ExtendedFloat::try_from_value(self.product * value)?.downgrade();
+        // The exact rounding error of the multiplication
+        let error = self.product.mul_add(value, -product);
+        self.compensation = self.compensation * value + error;
+        self.product = product;
+        Ok(())
+    }
+
+    #[inline]
+    fn value<Tol: Tolerance<T>>(&self) -> Result<TolerantFloat<T, Tol>, ConversionError> {
+        TolerantFloat::try_from_value(self.product + self.compensation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_sum() {
+        let values = vec![ExtendedFloat::new(0.1); 10];
+        let naive = values
+            .iter()
+            .fold(0.0, |sum, value| sum + value.downgrade());
+        assert_ne!(naive, 1.0);
+
+        let owned: ExtendedFloat<f64> = values.clone().into_iter().sum();
+        let borrowed: ExtendedFloat<f64> = values.iter().sum();
+        assert_eq!(owned.downgrade(), 1.0);
+        assert_eq!(borrowed.downgrade(), 1.0);
+
+        // Cancellation that a naive sum loses entirely
+        let values = [1e100, 1.0, -1e100].map(ExtendedFloat::new);
+        assert_eq!(values.iter().sum::<ExtendedFloat<f64>>().downgrade(), 1.0);
+
+        let empty: [ExtendedFloat<f64>; 0] = [];
+        assert_eq!(empty.iter().sum::<ExtendedFloat<f64>>().downgrade(), 0.0);
+    }
+
+    #[test]
+    fn test_sum_long_series() {
+        let values = vec![ExtendedFloat::new(0.01); 1_000_000];
+        let sum: ExtendedFloat<f64> = values.iter().sum();
+        assert_eq!(sum.downgrade(), 10_000.0);
+        assert_eq!(sum.format(), "10000");
+
+        let values = vec![ExtendedFloat::new(0.1_f32); 100_000];
+        let sum: ExtendedFloat<f32> = values.iter().sum();
+        assert_eq!(sum.downgrade(), 10_000.0);
+
+        let values = vec![ExtendedFloat::new(0.3_f32); 100_000];
+        let sum: ExtendedFloat<f32> = values.iter().sum();
+        assert_eq!(sum.format(), "30000");
+    }
+
+    #[test]
+    fn test_product() {
+        let values = [1.1, 1.1, 1.1].map(ExtendedFloat::new);
+        let owned: ExtendedFloat<f64> = values.into_iter().product();
+        let borrowed: ExtendedFloat<f64> = values.iter().product();
+        assert_eq!(owned, ExtendedFloat::new(1.331));
+        assert_eq!(borrowed.format(), "1.331");
+
+        // Compound growth over many periods
+        let values = vec![ExtendedFloat::new(1.01); 100];
+        let product: ExtendedFloat<f64> = values.iter().product();
+        assert_eq!(product.format(), "2.70481382942153");
+
+        let values = [ExtendedFloat::new(1.1_f32); 10];
+        let product: ExtendedFloat<f32> = values.iter().product();
+        assert_eq!(product.format(), "2.59374");
+
+        let empty: [ExtendedFloat<f64>; 0] = [];
+        assert_eq!(
+            empty.iter().product::<ExtendedFloat<f64>>().downgrade(),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_try_sum_and_product() {
+        let values = [f64::MAX, f64::MAX, -f64::MAX].map(ExtendedFloat::new);
+        assert_eq!(
+            ExtendedFloat::try_sum(values),
+            Err(ConversionError::Infinite)
+        );
+        assert_eq!(
+            ExtendedFloat::try_product(values.iter()),
+            Err(ConversionError::Infinite)
+        );
+
+        // Overflow is reported even if later values would bring the result back
+        let values = [1e200, 1e200, 0.0].map(ExtendedFloat::new);
+        assert_eq!(
+            ExtendedFloat::try_product(values),
+            Err(ConversionError::Infinite)
+        );
+
+        let values = [0.5, 0.25].map(ExtendedFloat::new);
+        assert_eq!(ExtendedFloat::try_sum(values).unwrap().downgrade(), 0.75);
+        assert_eq!(
+            ExtendedFloat::try_product(values).unwrap().downgrade(),
+            0.125
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support infinite values")]
+    fn test_sum_overflow() {
+        let values = [f64::MAX, f64::MAX].map(ExtendedFloat::new);
+        let _: ExtendedFloat<f64> = values.iter().sum();
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support infinite values")]
+    fn test_product_overflow() {
+        let values = [f32::MAX, 2.0].map(ExtendedFloat::new);
+        let _: ExtendedFloat<f32> = values.into_iter().product();
+    }
+
+    #[test]
+    fn test_dot() {
+        let weights = [0.1, 0.2, 0.3, 0.4].map(ExtendedFloat::new);
+        let prices = [101.3, 99.7, 100.1, 100.4].map(ExtendedFloat::new);
+        assert_eq!(ExtendedFloat::dot(&weights, &prices).downgrade(), 100.26);
+
+        // Cancellation a naive dot product gets wrong
+        let a = [1e16, 1.0, -1e16].map(ExtendedFloat::new);
+        let b = [1.0, 1.0, 1.0].map(ExtendedFloat::new);
+        assert_eq!(ExtendedFloat::dot(&a, &b).downgrade(), 1.0);
+
+        // Rounding errors of the products are kept
+        let a = [0.1, -1.0].map(ExtendedFloat::new);
+        let b = [10.0, 1.0].map(ExtendedFloat::new);
+        assert_eq!(
+            ExtendedFloat::dot(&a, &b).downgrade(),
+            0.1_f64.mul_add(10.0, -1.0)
+        );
+
+        assert_eq!(ExtendedFloat::<f64>::dot(&[], &[]).downgrade(), 0.0);
+
+        let a = [0.1_f32; 1000].map(ExtendedFloat::new);
+        let b = [0.3_f32; 1000].map(ExtendedFloat::new);
+        assert_eq!(ExtendedFloat::dot(&a, &b).format(), "30");
+    }
+
+    #[test]
+    fn test_try_dot() {
+        let huge = [f64::MAX, 1.0].map(ExtendedFloat::new);
+        let two = [2.0, 1.0].map(ExtendedFloat::new);
+        assert_eq!(
+            ExtendedFloat::try_dot(&huge, &two),
+            Err(ConversionError::Infinite)
+        );
+
+        let ones = [1.0, 1.0].map(ExtendedFloat::new);
+        assert_eq!(
+            ExtendedFloat::try_dot(&[huge[0], huge[0]], &ones),
+            Err(ConversionError::Infinite)
+        );
+        assert_eq!(
+            ExtendedFloat::try_dot(&ones, &two).unwrap().downgrade(),
+            3.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Dot product of slices with different lengths")]
+    fn test_dot_length_mismatch() {
+        let _ = ExtendedFloat::dot(&[ExtendedFloat::new(1.0)], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Dot product of slices with different lengths")]
+    fn test_try_dot_length_mismatch() {
+        let _ = ExtendedFloat::try_dot(&[], &[ExtendedFloat::new(1.0)]);
+    }
+}
@@ -0,0 +1,344 @@
+use super::{ConversionError, TolerantFloat};
+use crate::traits::{CheckedFloatMath, DisplayableFloat, Tolerance};
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
+    /// Computes the square root.
+    ///
+    /// Negative values that the `Tol` policy considers equal to zero are zero, so
+    /// artifacts like `0.3 - (0.1 + 0.2)` don't fail.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value is negative.
+    ///
+    /// For a non-panicking version, use `checked_sqrt` or `try_sqrt`.
+    pub fn sqrt(&self) -> Self {
+        self.try_sqrt().unwrap_or_else(|error| error.panic())
+    }
+
+    /// Computes `self * a + b` with a single rounding, see [`f64::mul_add`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the result would be NaN or infinite (e.g., overflow).
+    ///
+    /// For a non-panicking version, use `checked_mul_add` or `try_mul_add`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let price = ExtendedFloat::new(101.25);
+    /// let fee = ExtendedFloat::new(0.35);
+    /// assert_eq!(price.mul_add(ExtendedFloat::new(4.0), fee).format(), "405.35");
+    /// ```
+    pub fn mul_add(&self, a: Self, b: Self) -> Self {
+        Self::new(self.downgrade().mul_add(a.downgrade(), b.downgrade()))
+    }
+
+    /// Raises the value to an integer power.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the result would be infinite (e.g., overflow or
+    /// a negative power of zero).
+    ///
+    /// For a non-panicking version, use `checked_powi` or `try_powi`.
+    pub fn powi(&self, n: i32) -> Self {
+        Self::new(self.downgrade().powi(n))
+    }
+
+    /// Raises the value to a power.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the result would be NaN or infinite (e.g., a
+    /// fractional power of a negative value).
+    ///
+    /// For a non-panicking version, use `checked_powf` or `try_powf`.
+    pub fn powf(&self, n: Self) -> Self {
+        Self::new(self.downgrade().powf(n.downgrade()))
+    }
+
+    /// Computes `e^self`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the result would be infinite (overflow).
+    ///
+    /// For a non-panicking version, use `checked_exp` or `try_exp`.
+    pub fn exp(&self) -> Self {
+        Self::new(self.downgrade().exp())
+    }
+
+    /// Computes the natural logarithm.
+    ///
+    /// Unlike [`sqrt`](Self::sqrt), negative values equal to zero under the policy
+    /// aren't treated as zero: the logarithm of zero is infinite, so such artifacts would
+    /// fail either way. Positive values that small are taken as they are.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value is zero or negative.
+    ///
+    /// For a non-panicking version, use `checked_ln` or `try_ln`.
+    pub fn ln(&self) -> Self {
+        Self::new(self.downgrade().ln())
+    }
+
+    /// Computes the base 10 logarithm.
+    ///
+    /// Values near zero are handled like in [`ln`](Self::ln).
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value is zero or negative.
+    ///
+    /// For a non-panicking version, use `checked_log10` or `try_log10`.
+    pub fn log10(&self) -> Self {
+        Self::new(self.downgrade().log10())
+    }
+
+    /// Computes the base 2 logarithm.
+    ///
+    /// Values near zero are handled like in [`ln`](Self::ln).
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value is zero or negative.
+    ///
+    /// For a non-panicking version, use `checked_log2` or `try_log2`.
+    pub fn log2(&self) -> Self {
+        Self::new(self.downgrade().log2())
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> CheckedFloatMath for TolerantFloat<T, Tol> {
+    fn checked_sqrt(&self) -> Option<Self> {
+        self.try_sqrt().ok()
+    }
+
+    fn checked_powi(&self, n: i32) -> Option<Self> {
+        Self::try_new(self.downgrade().powi(n))
+    }
+
+    fn checked_powf(&self, n: Self) -> Option<Self> {
+        Self::try_new(self.downgrade().powf(n.downgrade()))
+    }
+
+    fn checked_exp(&self) -> Option<Self> {
+        Self::try_new(self.downgrade().exp())
+    }
+
+    fn checked_ln(&self) -> Option<Self> {
+        Self::try_new(self.downgrade().ln())
+    }
+
+    fn checked_log10(&self) -> Option<Self> {
+        Self::try_new(self.downgrade().log10())
+    }
+
+    fn checked_log2(&self) -> Option<Self> {
+        Self::try_new(self.downgrade().log2())
+    }
+
+    fn try_sqrt(&self) -> Result<Self, ConversionError> {
+        let value = self.downgrade();
+        if value < T::zero() && Tol::approx_eq(value, T::zero()) {
+            return Ok(Self::new(T::zero()));
+        }
+        Self::try_from_value(value.sqrt())
+    }
+
+    fn try_powi(&self, n: i32) -> Result<Self, ConversionError> {
+        Self::try_from_value(self.downgrade().powi(n))
+    }
+
+    fn try_powf(&self, n: Self) -> Result<Self, ConversionError> {
+        Self::try_from_value(self.downgrade().powf(n.downgrade()))
+    }
+
+    fn try_exp(&self) -> Result<Self, ConversionError> {
+        Self::try_from_value(self.downgrade().exp())
+    }
+
+    fn try_ln(&self) -> Result<Self, ConversionError> {
+        Self::try_from_value(self.downgrade().ln())
+    }
+
+    fn try_log10(&self) -> Result<Self, ConversionError> {
+        Self::try_from_value(self.downgrade().log10())
+    }
+
+    fn try_log2(&self) -> Result<Self, ConversionError> {
+        Self::try_from_value(self.downgrade().log2())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::E;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::types::ExtendedFloat;
+    use crate::types::extended_float::Absolute;
+
+    #[test]
+    fn test_math_functions() {
+        assert_eq!(ExtendedFloat::new(16.0).sqrt(), ExtendedFloat::new(4.0));
+        assert_eq!(
+            ExtendedFloat::new(2.0).sqrt(),
+            ExtendedFloat::new(2f64.sqrt())
+        );
+        assert_eq!(ExtendedFloat::new(1.1).powi(2), ExtendedFloat::new(1.21));
+        assert_eq!(ExtendedFloat::new(2.0).powi(-2), ExtendedFloat::new(0.25));
+        assert_eq!(
+            ExtendedFloat::new(8.0).powf(ExtendedFloat::new(1.0 / 3.0)),
+            ExtendedFloat::new(2.0)
+        );
+        assert_eq!(ExtendedFloat::new(1.0).exp(), ExtendedFloat::new(E));
+        assert_eq!(ExtendedFloat::new(E).ln(), ExtendedFloat::new(1.0));
+        assert_eq!(ExtendedFloat::new(1000.0).log10(), ExtendedFloat::new(3.0));
+        assert_eq!(ExtendedFloat::new(0.125).log2(), ExtendedFloat::new(-3.0));
+
+        assert_eq!(
+            ExtendedFloat::new(2.25_f32).sqrt(),
+            ExtendedFloat::new(1.5_f32)
+        );
+        assert_eq!(
+            ExtendedFloat::new(100.0_f32).log10(),
+            ExtendedFloat::new(2.0_f32)
+        );
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let value = ExtendedFloat::new(0.1);
+        assert_eq!(
+            value.mul_add(ExtendedFloat::new(3.0), ExtendedFloat::new(0.7)),
+            ExtendedFloat::new(1.0)
+        );
+        assert_eq!(
+            ExtendedFloat::new(1.5_f32).mul_add(ExtendedFloat::new(2.0), ExtendedFloat::new(-3.0)),
+            ExtendedFloat::new(0.0_f32)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support infinite values")]
+    fn test_mul_add_overflow() {
+        let huge = ExtendedFloat::new(f64::MAX);
+        let _ = huge.mul_add(huge, ExtendedFloat::new(0.0));
+    }
+
+    #[test]
+    fn test_sqrt_of_negative_artifact() {
+        let value = ExtendedFloat::new(0.3) - (ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2));
+        assert!(value.downgrade() < 0.0);
+        assert_eq!(value.sqrt().downgrade(), 0.0);
+        assert_eq!(value.try_sqrt(), Ok(ExtendedFloat::new(0.0)));
+
+        // Only values equal to zero under the policy are clamped
+        type Fine = TolerantFloat<f64, Absolute<16>>;
+        assert_eq!(Fine::new(-1e-13).try_sqrt(), Err(ConversionError::NaN));
+        assert_eq!(Fine::new(-1e-17).try_sqrt().unwrap().downgrade(), 0.0);
+        assert_eq!(
+            ExtendedFloat::new(-1e-13).try_sqrt().unwrap().downgrade(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_log_of_near_zero() {
+        let value = ExtendedFloat::new(0.3) - (ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2));
+        assert_eq!(value.try_ln(), Err(ConversionError::NaN));
+        assert_eq!(value.try_log10(), Err(ConversionError::NaN));
+        assert_eq!((-value).try_log2().unwrap().downgrade(), -54.0);
+        assert_eq!(
+            ExtendedFloat::new(0.0).try_log10(),
+            Err(ConversionError::Infinite)
+        );
+    }
+
+    #[test]
+    fn test_checked_math() {
+        assert_eq!(
+            ExtendedFloat::new(9.0).checked_sqrt(),
+            Some(ExtendedFloat::new(3.0))
+        );
+        assert!(ExtendedFloat::new(-1.0).checked_sqrt().is_none());
+        assert!(ExtendedFloat::new(0.0).checked_powi(-1).is_none());
+        assert!(ExtendedFloat::new(10.0).checked_powi(400).is_none());
+        assert!(
+            ExtendedFloat::new(-8.0)
+                .checked_powf(ExtendedFloat::new(0.5))
+                .is_none()
+        );
+        assert!(ExtendedFloat::new(1000.0).checked_exp().is_none());
+        assert!(ExtendedFloat::new(0.0).checked_ln().is_none());
+        assert!(ExtendedFloat::new(-1.0).checked_log10().is_none());
+        assert!(ExtendedFloat::new(0.0).checked_log2().is_none());
+        assert_eq!(
+            ExtendedFloat::new(-1000.0).checked_exp(),
+            Some(ExtendedFloat::new(0.0))
+        );
+    }
+
+    #[test]
+    fn test_try_math() {
+        assert_eq!(
+            ExtendedFloat::new(-1.0).try_sqrt(),
+            Err(ConversionError::NaN)
+        );
+        assert_eq!(
+            ExtendedFloat::new(0.0).try_powi(-1),
+            Err(ConversionError::Infinite)
+        );
+        assert_eq!(
+            ExtendedFloat::new(-8.0).try_powf(ExtendedFloat::new(0.5)),
+            Err(ConversionError::NaN)
+        );
+        assert_eq!(
+            ExtendedFloat::new(1000.0).try_exp(),
+            Err(ConversionError::Infinite)
+        );
+        assert_eq!(
+            ExtendedFloat::new(0.0).try_ln(),
+            Err(ConversionError::Infinite)
+        );
+        assert_eq!(ExtendedFloat::new(-1.0).try_ln(), Err(ConversionError::NaN));
+        assert_eq!(
+            ExtendedFloat::new(0.0).try_log10(),
+            Err(ConversionError::Infinite)
+        );
+        assert_eq!(
+            ExtendedFloat::new(-2.0_f32).try_log2(),
+            Err(ConversionError::NaN)
+        );
+        assert_eq!(
+            ExtendedFloat::new(4.0).try_log2(),
+            Ok(ExtendedFloat::new(2.0))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support NaN values")]
+    fn test_sqrt_negative() {
+        let _ = ExtendedFloat::new(-1.0).sqrt();
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support infinite values")]
+    fn test_ln_zero() {
+        let _ = ExtendedFloat::new(0.0).ln();
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support infinite values")]
+    fn test_exp_overflow() {
+        let _ = ExtendedFloat::new(100.0_f32).exp();
+    }
+}
@@ -2,10 +2,10 @@ use std::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
 
-use super::ExtendedFloat;
-use crate::traits::DisplayableFloat;
+use super::TolerantFloat;
+use crate::traits::{DisplayableFloat, Tolerance};
 
-impl<T: DisplayableFloat> Sub for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Sub for TolerantFloat<T, Tol> {
     type Output = Self;
 
     /// Subtracts two ExtendedFloat values.
@@ -22,7 +22,7 @@ impl<T: DisplayableFloat> Sub for ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> SubAssign for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> SubAssign for TolerantFloat<T, Tol> {
     /// Subtracts another ExtendedFloat value from this one.
     ///
     /// # Panics
@@ -35,7 +35,7 @@ impl<T: DisplayableFloat> SubAssign for ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> Add for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Add for TolerantFloat<T, Tol> {
     type Output = Self;
 
     /// Adds two ExtendedFloat values.
@@ -52,7 +52,7 @@ impl<T: DisplayableFloat> Add for ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> AddAssign for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> AddAssign for TolerantFloat<T, Tol> {
     /// Adds another ExtendedFloat value to this one.
     ///
     /// # Panics
@@ -65,7 +65,7 @@ impl<T: DisplayableFloat> AddAssign for ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> Mul for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Mul for TolerantFloat<T, Tol> {
     type Output = Self;
 
     /// Multiplies two ExtendedFloat values.
@@ -82,7 +82,7 @@ impl<T: DisplayableFloat> Mul for ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> MulAssign for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> MulAssign for TolerantFloat<T, Tol> {
     /// Multiplies this ExtendedFloat value by another.
     ///
     /// # Panics
@@ -95,7 +95,7 @@ impl<T: DisplayableFloat> MulAssign for ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> Div for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Div for TolerantFloat<T, Tol> {
     type Output = Self;
 
     /// Divides two ExtendedFloat values.
@@ -112,7 +112,7 @@ impl<T: DisplayableFloat> Div for ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> DivAssign for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> DivAssign for TolerantFloat<T, Tol> {
     /// Divides this ExtendedFloat value by another.
     ///
     /// # Panics
@@ -125,7 +125,7 @@ impl<T: DisplayableFloat> DivAssign for ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> Rem for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Rem for TolerantFloat<T, Tol> {
     type Output = Self;
 
     /// Calculates the remainder of the division of two ExtendedFloat values.
@@ -142,7 +142,7 @@ impl<T: DisplayableFloat> Rem for ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> RemAssign for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> RemAssign for TolerantFloat<T, Tol> {
     /// Sets this ExtendedFloat value to the remainder of division by another.
     ///
     /// # Panics
@@ -155,7 +155,7 @@ impl<T: DisplayableFloat> RemAssign for ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> Neg for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Neg for TolerantFloat<T, Tol> {
     type Output = Self;
 
     /// Negates this ExtendedFloat value.
@@ -168,12 +168,394 @@ impl<T: DisplayableFloat> Neg for ExtendedFloat<T> {
     }
 }
 
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Sub<T> for TolerantFloat<T, Tol> {
+    type Output = Self;
+
+    /// Subtracts a raw value from an ExtendedFloat value.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - Either operand contains an invalid value (NaN, infinity)
+    /// - The result would be NaN or infinite (e.g., overflow)
+    ///
+    /// For a non-panicking version, use `checked_sub` or `try_sub`.
+    fn sub(self, other: T) -> Self::Output {
+        self - Self::new(other)
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> SubAssign<T> for TolerantFloat<T, Tol> {
+    /// Subtracts a raw value from this ExtendedFloat value.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - Either operand contains an invalid value (NaN, infinity)
+    /// - The result would be NaN or infinite (e.g., overflow)
+    fn sub_assign(&mut self, other: T) {
+        *self -= Self::new(other);
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Add<T> for TolerantFloat<T, Tol> {
+    type Output = Self;
+
+    /// Adds a raw value to an ExtendedFloat value.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - Either operand contains an invalid value (NaN, infinity)
+    /// - The result would be NaN or infinite (e.g., overflow)
+    ///
+    /// For a non-panicking version, use `checked_add` or `try_add`.
+    fn add(self, other: T) -> Self::Output {
+        self + Self::new(other)
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> AddAssign<T> for TolerantFloat<T, Tol> {
+    /// Adds a raw value to this ExtendedFloat value.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - Either operand contains an invalid value (NaN, infinity)
+    /// - The result would be NaN or infinite (e.g., overflow)
+    fn add_assign(&mut self, other: T) {
+        *self += Self::new(other);
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Mul<T> for TolerantFloat<T, Tol> {
+    type Output = Self;
+
+    /// Multiplies an ExtendedFloat value by a raw value.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - Either operand contains an invalid value (NaN, infinity)
+    /// - The result would be NaN or infinite (e.g., overflow)
+    ///
+    /// For a non-panicking version, use `checked_mul` or `try_mul`.
+    fn mul(self, other: T) -> Self::Output {
+        self * Self::new(other)
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> MulAssign<T> for TolerantFloat<T, Tol> {
+    /// Multiplies this ExtendedFloat value by a raw value.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - Either operand contains an invalid value (NaN, infinity)
+    /// - The result would be NaN or infinite (e.g., overflow)
+    fn mul_assign(&mut self, other: T) {
+        *self *= Self::new(other);
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Div<T> for TolerantFloat<T, Tol> {
+    type Output = Self;
+
+    /// Divides an ExtendedFloat value by a raw value.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - Either operand contains an invalid value (NaN, infinity)
+    /// - The result would be NaN or infinite (e.g., division by zero)
+    ///
+    /// For a non-panicking version, use `checked_div` or `try_div`.
+    fn div(self, other: T) -> Self::Output {
+        self / Self::new(other)
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> DivAssign<T> for TolerantFloat<T, Tol> {
+    /// Divides this ExtendedFloat value by a raw value.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - Either operand contains an invalid value (NaN, infinity)
+    /// - The result would be NaN or infinite (e.g., division by zero)
+    fn div_assign(&mut self, other: T) {
+        *self /= Self::new(other);
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Rem<T> for TolerantFloat<T, Tol> {
+    type Output = Self;
+
+    /// Calculates the remainder of the division of an ExtendedFloat value by a raw value.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - Either operand contains an invalid value (NaN, infinity)
+    /// - The result would be NaN or infinite (e.g., remainder by zero)
+    ///
+    /// For a non-panicking version, use `checked_rem` or `try_rem`.
+    fn rem(self, other: T) -> Self::Output {
+        self % Self::new(other)
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> RemAssign<T> for TolerantFloat<T, Tol> {
+    /// Sets this ExtendedFloat value to the remainder of division by a raw value.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if:
+    /// - Either operand contains an invalid value (NaN, infinity)
+    /// - The result would be NaN or infinite (e.g., remainder by zero)
+    fn rem_assign(&mut self, other: T) {
+        *self %= Self::new(other);
+    }
+}
+
+/// Forwards a binary operator to the by-value impl for every combination of
+/// references, with either a `TolerantFloat` or a raw value on the right-hand side,
+/// the way `std` does for primitive types
+macro_rules! forward_ref_binop {
+    ($($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident);* $(;)?) => {$(
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> $imp<&TolerantFloat<T, Tol>> for TolerantFloat<T, Tol> {
+            type Output = TolerantFloat<T, Tol>;
+
+            #[inline]
+            fn $method(self, other: &TolerantFloat<T, Tol>) -> Self::Output {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> $imp<TolerantFloat<T, Tol>> for &TolerantFloat<T, Tol> {
+            type Output = TolerantFloat<T, Tol>;
+
+            #[inline]
+            fn $method(self, other: TolerantFloat<T, Tol>) -> Self::Output {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> $imp<&TolerantFloat<T, Tol>> for &TolerantFloat<T, Tol> {
+            type Output = TolerantFloat<T, Tol>;
+
+            #[inline]
+            fn $method(self, other: &TolerantFloat<T, Tol>) -> Self::Output {
+                $imp::$method(*self, *other)
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> $assign_imp<&TolerantFloat<T, Tol>> for TolerantFloat<T, Tol> {
+            #[inline]
+            fn $assign_method(&mut self, other: &TolerantFloat<T, Tol>) {
+                $assign_imp::$assign_method(self, *other);
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> $imp<T> for &TolerantFloat<T, Tol> {
+            type Output = TolerantFloat<T, Tol>;
+
+            #[inline]
+            fn $method(self, other: T) -> Self::Output {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> $imp<&T> for TolerantFloat<T, Tol> {
+            type Output = TolerantFloat<T, Tol>;
+
+            #[inline]
+            fn $method(self, other: &T) -> Self::Output {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> $imp<&T> for &TolerantFloat<T, Tol> {
+            type Output = TolerantFloat<T, Tol>;
+
+            #[inline]
+            fn $method(self, other: &T) -> Self::Output {
+                $imp::$method(*self, *other)
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> $assign_imp<&T> for TolerantFloat<T, Tol> {
+            #[inline]
+            fn $assign_method(&mut self, other: &T) {
+                $assign_imp::$assign_method(self, *other);
+            }
+        }
+    )*};
+}
+
+forward_ref_binop! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign;
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Neg for &TolerantFloat<T, Tol> {
+    type Output = TolerantFloat<T, Tol>;
+
+    /// Negates the referenced ExtendedFloat value.
+    ///
+    /// # Panics
+    ///
+    /// This operation will panic if the operand contains an invalid value (NaN, infinity).
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+/// Implements arithmetic with the raw float on the left-hand side. These can't be
+/// generic over `T`, as the orphan rule only allows them for concrete types.
+macro_rules! impl_raw_lhs_ops {
+    ($($float:ty),*) => {$(
+        impl<Tol: Tolerance<$float>> Sub<TolerantFloat<$float, Tol>> for $float {
+            type Output = TolerantFloat<$float, Tol>;
+
+            /// Subtracts an ExtendedFloat value from a raw value.
+            ///
+            /// # Panics
+            ///
+            /// This operation will panic if:
+            /// - Either operand contains an invalid value (NaN, infinity)
+            /// - The result would be NaN or infinite (e.g., overflow)
+            fn sub(self, other: TolerantFloat<$float, Tol>) -> Self::Output {
+                TolerantFloat::new(self) - other
+            }
+        }
+
+        impl<Tol: Tolerance<$float>> Add<TolerantFloat<$float, Tol>> for $float {
+            type Output = TolerantFloat<$float, Tol>;
+
+            /// Adds an ExtendedFloat value to a raw value.
+            ///
+            /// # Panics
+            ///
+            /// This operation will panic if:
+            /// - Either operand contains an invalid value (NaN, infinity)
+            /// - The result would be NaN or infinite (e.g., overflow)
+            fn add(self, other: TolerantFloat<$float, Tol>) -> Self::Output {
+                TolerantFloat::new(self) + other
+            }
+        }
+
+        impl<Tol: Tolerance<$float>> Mul<TolerantFloat<$float, Tol>> for $float {
+            type Output = TolerantFloat<$float, Tol>;
+
+            /// Multiplies a raw value by an ExtendedFloat value.
+            ///
+            /// # Panics
+            ///
+            /// This operation will panic if:
+            /// - Either operand contains an invalid value (NaN, infinity)
+            /// - The result would be NaN or infinite (e.g., overflow)
+            fn mul(self, other: TolerantFloat<$float, Tol>) -> Self::Output {
+                TolerantFloat::new(self) * other
+            }
+        }
+
+        impl<Tol: Tolerance<$float>> Div<TolerantFloat<$float, Tol>> for $float {
+            type Output = TolerantFloat<$float, Tol>;
+
+            /// Divides a raw value by an ExtendedFloat value.
+            ///
+            /// # Panics
+            ///
+            /// This operation will panic if:
+            /// - Either operand contains an invalid value (NaN, infinity)
+            /// - The result would be NaN or infinite (e.g., division by zero)
+            fn div(self, other: TolerantFloat<$float, Tol>) -> Self::Output {
+                TolerantFloat::new(self) / other
+            }
+        }
+
+        impl<Tol: Tolerance<$float>> Rem<TolerantFloat<$float, Tol>> for $float {
+            type Output = TolerantFloat<$float, Tol>;
+
+            /// Calculates the remainder of the division of a raw value by an ExtendedFloat value.
+            ///
+            /// # Panics
+            ///
+            /// This operation will panic if:
+            /// - Either operand contains an invalid value (NaN, infinity)
+            /// - The result would be NaN or infinite (e.g., remainder by zero)
+            fn rem(self, other: TolerantFloat<$float, Tol>) -> Self::Output {
+                TolerantFloat::new(self) % other
+            }
+        }
+    )*};
+}
+
+impl_raw_lhs_ops!(f32, f64);
+
+/// Forwards arithmetic with the raw float on the left-hand side to the by-value impl
+/// for every combination of references
+macro_rules! forward_ref_raw_lhs_binop {
+    ($float:ty; $($imp:ident, $method:ident);* $(;)?) => {$(
+        impl<Tol: Tolerance<$float>> $imp<&TolerantFloat<$float, Tol>> for $float {
+            type Output = TolerantFloat<$float, Tol>;
+
+            #[inline]
+            fn $method(self, other: &TolerantFloat<$float, Tol>) -> Self::Output {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl<Tol: Tolerance<$float>> $imp<TolerantFloat<$float, Tol>> for &$float {
+            type Output = TolerantFloat<$float, Tol>;
+
+            #[inline]
+            fn $method(self, other: TolerantFloat<$float, Tol>) -> Self::Output {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl<Tol: Tolerance<$float>> $imp<&TolerantFloat<$float, Tol>> for &$float {
+            type Output = TolerantFloat<$float, Tol>;
+
+            #[inline]
+            fn $method(self, other: &TolerantFloat<$float, Tol>) -> Self::Output {
+                $imp::$method(*self, *other)
+            }
+        }
+    )*};
+}
+
+forward_ref_raw_lhs_binop! {
+    f32;
+    Add, add;
+    Sub, sub;
+    Mul, mul;
+    Div, div;
+    Rem, rem;
+}
+
+forward_ref_raw_lhs_binop! {
+    f64;
+    Add, add;
+    Sub, sub;
+    Mul, mul;
+    Div, div;
+    Rem, rem;
+}
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::{E, PI};
 
     use super::*;
     use crate::constants::f64::EPSILON as EPSILON_F64;
+    use crate::types::ExtendedFloat;
 
     #[test]
     fn test_addition() {
@@ -643,6 +1025,155 @@ mod tests {
     fn test_modulo_by_zero() {
         let _ = ExtendedFloat::new(5.0) % ExtendedFloat::new(0.0);
     }
+
+    #[test]
+    #[allow(clippy::op_ref)] // References are what is tested here
+    fn test_reference_operands() {
+        let a = ExtendedFloat::new(0.1);
+        let b = ExtendedFloat::new(0.2);
+
+        assert_eq!(&a + &b, ExtendedFloat::new(0.3));
+        assert_eq!(&a + b, ExtendedFloat::new(0.3));
+        assert_eq!(a + &b, ExtendedFloat::new(0.3));
+        assert_eq!(&b - &a, ExtendedFloat::new(0.1));
+        assert_eq!(&a * &b, ExtendedFloat::new(0.02));
+        assert_eq!(&b / a, ExtendedFloat::new(2.0));
+        assert_eq!(b % &a, ExtendedFloat::new(0.0));
+        assert_eq!(-&a, ExtendedFloat::new(-0.1));
+
+        let mut c = a;
+        c += &b;
+        c -= &a;
+        c *= &b;
+        c /= &b;
+        c %= &a;
+        assert_eq!(c, ExtendedFloat::new(0.0));
+
+        // Raw operands on either side
+        assert_eq!(&a * 2.0, ExtendedFloat::new(0.2));
+        assert_eq!(a * &2.0, ExtendedFloat::new(0.2));
+        assert_eq!(&a + &0.2, ExtendedFloat::new(0.3));
+        assert_eq!(&b - 0.1, ExtendedFloat::new(0.1));
+        assert_eq!(b / &2.0, ExtendedFloat::new(0.1));
+        assert_eq!(&b % &0.2, ExtendedFloat::new(0.0));
+        assert_eq!(2.0 * &a, ExtendedFloat::new(0.2));
+        assert_eq!(&0.3 - a, ExtendedFloat::new(0.2));
+        assert_eq!(&1.0 / &b, ExtendedFloat::new(5.0));
+        assert_eq!(0.1 + &b, ExtendedFloat::new(0.3));
+        assert_eq!(&0.5 % &b, ExtendedFloat::new(0.1));
+
+        let mut c = a;
+        c += &0.2;
+        c -= &0.1;
+        c *= &3.0;
+        c /= &0.3;
+        c %= &0.75;
+        assert_eq!(c, ExtendedFloat::new(0.5));
+
+        let a = ExtendedFloat::new(0.1_f32);
+        assert_eq!(&a * &3.0_f32, ExtendedFloat::new(0.3_f32));
+        assert_eq!(&3.0_f32 * &a, ExtendedFloat::new(0.3_f32));
+        assert_eq!(3.0_f32 * &a, ExtendedFloat::new(0.3_f32));
+        assert_eq!(&3.0_f32 * a, ExtendedFloat::new(0.3_f32));
+    }
+
+    #[test]
+    fn test_reference_operands_generic() {
+        // Code written against reference arithmetic bounds, as in numeric libraries
+        fn dot<T>(a: &[T], b: &[T], zero: T) -> T
+        where
+            for<'a> &'a T: Add<&'a T, Output = T> + Mul<&'a T, Output = T>,
+        {
+            a.iter().zip(b).fold(zero, |sum, (a, b)| &sum + &(a * b))
+        }
+
+        fn negate_all<T>(values: &[T]) -> Vec<T>
+        where
+            for<'a> &'a T: Neg<Output = T>,
+        {
+            values.iter().map(|value| -value).collect()
+        }
+
+        let a = [ExtendedFloat::new(0.1), ExtendedFloat::new(0.2)];
+        let b = [ExtendedFloat::new(3.0), ExtendedFloat::new(6.0)];
+        assert_eq!(
+            dot(&a, &b, ExtendedFloat::new(0.0)),
+            ExtendedFloat::new(1.5)
+        );
+        assert_eq!(
+            dot(&negate_all(&a), &b, ExtendedFloat::new(0.0)),
+            ExtendedFloat::new(-1.5)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support infinite values")]
+    fn test_reference_operands_overflow() {
+        let huge = &ExtendedFloat::new(f64::MAX);
+        let _ = huge * huge;
+    }
+
+    #[test]
+    fn test_raw_operands() {
+        let price = ExtendedFloat::new(0.1);
+        assert_eq!(price + 0.2, ExtendedFloat::new(0.3));
+        assert_eq!(price - 0.3, ExtendedFloat::new(-0.2));
+        assert_eq!(price * 3.0, ExtendedFloat::new(0.3));
+        assert_eq!(price / 0.5, ExtendedFloat::new(0.2));
+        assert_eq!(ExtendedFloat::new(7.0) % 4.0, ExtendedFloat::new(3.0));
+
+        assert_eq!(0.2 + price, ExtendedFloat::new(0.3));
+        assert_eq!(0.3 - price, ExtendedFloat::new(0.2));
+        assert_eq!(3.0 * price, ExtendedFloat::new(0.3));
+        assert_eq!(1.0 / price, ExtendedFloat::new(10.0));
+        assert_eq!(7.0 % ExtendedFloat::new(4.0), ExtendedFloat::new(3.0));
+
+        let price = ExtendedFloat::new(0.1_f32);
+        assert_eq!(price * 3.0, ExtendedFloat::new(0.3_f32));
+        assert_eq!(3.0_f32 * price, ExtendedFloat::new(0.3_f32));
+    }
+
+    #[test]
+    fn test_raw_operand_assignment() {
+        let mut a = ExtendedFloat::new(10.0);
+        a += 0.1;
+        a += 0.2;
+        assert_eq!(a, ExtendedFloat::new(10.3));
+        a -= 0.3;
+        assert_eq!(a, ExtendedFloat::new(10.0));
+        a *= 1.5;
+        assert_eq!(a, ExtendedFloat::new(15.0));
+        a /= 4.0;
+        assert_eq!(a, ExtendedFloat::new(3.75));
+        a %= 2.0;
+        assert_eq!(a, ExtendedFloat::new(1.75));
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support NaN values")]
+    fn test_raw_operand_nan() {
+        let _ = ExtendedFloat::new(1.0) + f64::NAN;
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support infinite values")]
+    fn test_raw_operand_infinite() {
+        // Validated even when the result would be finite
+        let _ = ExtendedFloat::new(1.0) / f64::INFINITY;
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support NaN values")]
+    fn test_raw_lhs_operand_nan() {
+        let _ = f64::NAN * ExtendedFloat::new(1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support infinite values")]
+    fn test_raw_operand_assignment_infinite() {
+        let mut a = ExtendedFloat::new(1.0_f32);
+        a -= f32::INFINITY;
+    }
 }
 
 // TODO: benches
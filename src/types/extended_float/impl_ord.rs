@@ -1,11 +1,45 @@
 use std::cmp::{Ord, Ordering, PartialOrd};
 
-use super::ExtendedFloat;
-use crate::traits::DisplayableFloat;
+use super::TolerantFloat;
+use crate::traits::{DisplayableFloat, Tolerance};
 
 // TODO: bench
 
-impl<T: DisplayableFloat> Ord for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
+    /// Compares the exact values, ignoring the tolerance.
+    ///
+    /// Unlike [`cmp`](Ord::cmp), this is a total order: it is transitive and equal
+    /// means identical, except that `-0.0` equals `0.0`. See [`TotalOrd`](super::TotalOrd)
+    /// for a wrapper that sorts and keys collections by it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let sum = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+    /// assert_eq!(sum.cmp(&ExtendedFloat::new(0.3)), Ordering::Equal);
+    /// assert_eq!(sum.total_cmp(&ExtendedFloat::new(0.3)), Ordering::Greater);
+    /// assert_eq!(ExtendedFloat::new(-0.0).total_cmp(&ExtendedFloat::new(0.0)), Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        // Positions among all floats are ordered like the values and put both
+        // zeros at zero
+        self.downgrade()
+            .ulp_index()
+            .cmp(&other.downgrade().ulp_index())
+    }
+}
+
+/// Orders the values with the tolerance: values that compare equal are `Equal`.
+///
+/// The tolerant equality isn't transitive, `a == b` and `b == c` don't imply
+/// `a == c`, so neither is this order. Sorting, `BTreeMap` keys and `binary_search`
+/// need a total order, use [`TotalOrd`](super::TotalOrd) for them.
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Ord for TolerantFloat<T, Tol> {
     fn cmp(&self, other: &Self) -> Ordering {
         if self == other {
             return Ordering::Equal;
@@ -19,18 +53,44 @@ impl<T: DisplayableFloat> Ord for ExtendedFloat<T> {
     }
 }
 
-impl<T: DisplayableFloat> PartialOrd for ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> PartialOrd for TolerantFloat<T, Tol> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
+impl<T: DisplayableFloat, Tol: Tolerance<T>> PartialOrd<T> for TolerantFloat<T, Tol> {
+    /// Compares with a raw value using the same tolerance.
+    ///
+    /// The raw value is validated like [`TolerantFloat::new`], but instead of
+    /// panicking, NaN and infinite values are unordered.
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        TolerantFloat::try_new(*other).map(|other| self.cmp(&other))
+    }
+}
+
+/// Implements comparison with the raw float on the left-hand side, which the
+/// orphan rule only allows for concrete types
+macro_rules! impl_raw_lhs_ord {
+    ($($float:ty),*) => {$(
+        impl<Tol: Tolerance<$float>> PartialOrd<TolerantFloat<$float, Tol>> for $float {
+            fn partial_cmp(&self, other: &TolerantFloat<$float, Tol>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    )*};
+}
+
+impl_raw_lhs_ord!(f32, f64);
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::constants::f32::EPSILON as EPSILON_F32;
     use crate::constants::f64::EPSILON;
+    use crate::types::ExtendedFloat;
 
     #[test]
     fn test_ordering_basics() {
@@ -44,6 +104,37 @@ mod tests {
         assert!(ExtendedFloat::new(1.0 + EPSILON * 50.0) > ExtendedFloat::new(1.0));
     }
 
+    #[test]
+    fn test_ordering_f32() {
+        assert!(ExtendedFloat::new(2.0_f32) > ExtendedFloat::new(1.0_f32));
+        assert!(ExtendedFloat::new(-1.0_f32) < ExtendedFloat::new(1.0_f32));
+
+        assert!(ExtendedFloat::new(1.0_f32) == ExtendedFloat::new(1.0 + EPSILON_F32 * 0.5));
+        assert!(ExtendedFloat::new(1.0_f32) < ExtendedFloat::new(1.0 + EPSILON_F32 * 50.0));
+        assert!(ExtendedFloat::new(1.0 + EPSILON_F32 * 50.0) > ExtendedFloat::new(1.0_f32));
+    }
+
+    #[test]
+    fn test_raw_ordering() {
+        let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+        assert!(value <= 0.3);
+        assert!(value >= 0.3);
+        assert!(value < 0.4);
+        assert!(0.4 > value);
+        assert!(0.3 >= value);
+        assert!(0.2 < value);
+        assert!(ExtendedFloat::new(1.0_f32) > 0.5);
+
+        // Invalid raw values are unordered
+        assert_eq!(ExtendedFloat::new(1.0).partial_cmp(&f64::NAN), None);
+        assert_eq!(ExtendedFloat::new(1.0).partial_cmp(&f64::INFINITY), None);
+        assert_eq!(
+            f64::NEG_INFINITY.partial_cmp(&ExtendedFloat::new(1.0)),
+            None
+        );
+        assert!(!(ExtendedFloat::new(1.0) < f64::INFINITY));
+    }
+
     #[test]
     fn test_sorting() {
         let mut values = [
@@ -0,0 +1,519 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use super::{ParseError, ParseErrorKind, TolerantFloat};
+use crate::traits::{DisplayableFloat, Tolerance};
+
+/// Maximum number of significant digits accumulated into [`DecimalScan::mantissa`].
+///
+/// 10^19 - 1 is the largest run of nines that fits into u64.
+const MAX_MANTISSA_DIGITS: usize = 19;
+
+/// Powers of ten representable in u64, used by the exact fast path
+const POWERS_OF_TEN: [u64; 20] = {
+    let mut table = [1u64; 20];
+    let mut i = 1;
+    while i < table.len() {
+        table[i] = table[i - 1] * 10;
+        i += 1;
+    }
+    table
+};
+
+/// Layout of a decimal number found at the start of the input
+struct DecimalScan {
+    /// Number of bytes forming the number
+    len: usize,
+    /// Position of the exponent marker (`e` or `E`), if any
+    exponent_position: Option<usize>,
+    /// Whether the number starts with a minus sign
+    negative: bool,
+    /// Up to [`MAX_MANTISSA_DIGITS`] leading significant digits as an integer
+    mantissa: u64,
+    /// Decimal exponent to apply to `mantissa`
+    exponent: i64,
+    /// Whether non-zero significant digits didn't fit into `mantissa`
+    truncated: bool,
+}
+
+/// Scans an ASCII decimal number: `[+-]digits[.digits][(e|E)[+-]digits]`.
+///
+/// At least one digit is required in the significand, either before or after the
+/// decimal point. Scanning stops at the first byte that can't continue the number.
+/// Significant digits are accumulated along the way, so the bytes are visited once.
+fn scan_decimal(bytes: &[u8]) -> Result<DecimalScan, ParseError> {
+    let mut scan = DecimalScan {
+        len: 0,
+        exponent_position: None,
+        negative: false,
+        mantissa: 0,
+        exponent: 0,
+        truncated: false,
+    };
+    let mut pos = 0;
+
+    if let Some(sign @ (b'+' | b'-')) = bytes.first() {
+        scan.negative = *sign == b'-';
+        pos += 1;
+    }
+
+    let significand_start = pos;
+    if is_non_finite_literal(&bytes[pos..]) {
+        return Err(ParseError::new(ParseErrorKind::NonFinite, pos));
+    }
+
+    let mut mantissa_digits = 0;
+    let integer_start = pos;
+    while let Some(digit) = ascii_digit(bytes, pos) {
+        if mantissa_digits < MAX_MANTISSA_DIGITS {
+            if scan.mantissa != 0 || digit != 0 {
+                scan.mantissa = scan.mantissa * 10 + digit;
+                mantissa_digits += 1;
+            }
+        } else {
+            // Digits that don't fit still scale the integer part
+            scan.truncated |= digit != 0;
+            scan.exponent += 1;
+        }
+        pos += 1;
+    }
+    let mut digits = pos - integer_start;
+
+    if bytes.get(pos) == Some(&b'.') {
+        pos += 1;
+        let fraction_start = pos;
+        while let Some(digit) = ascii_digit(bytes, pos) {
+            if mantissa_digits < MAX_MANTISSA_DIGITS {
+                // Leading zeros of the fraction aren't significant, but shift the exponent
+                scan.exponent -= 1;
+                if scan.mantissa != 0 || digit != 0 {
+                    scan.mantissa = scan.mantissa * 10 + digit;
+                    mantissa_digits += 1;
+                }
+            } else {
+                scan.truncated |= digit != 0;
+            }
+            pos += 1;
+        }
+        digits += pos - fraction_start;
+    }
+
+    if digits == 0 {
+        // Nothing but a sign and/or a decimal point
+        if pos == bytes.len() {
+            return Err(ParseError::new(ParseErrorKind::Empty, significand_start));
+        }
+        return Err(ParseError::new(ParseErrorKind::InvalidDigit, pos));
+    }
+
+    if matches!(bytes.get(pos), Some(b'e' | b'E')) {
+        scan.exponent_position = Some(pos);
+        pos += 1;
+
+        let mut negative_exponent = false;
+        if let Some(sign @ (b'+' | b'-')) = bytes.get(pos) {
+            negative_exponent = *sign == b'-';
+            pos += 1;
+        }
+
+        let exponent_start = pos;
+        let mut explicit_exponent: i64 = 0;
+        while let Some(digit) = ascii_digit(bytes, pos) {
+            // Saturate far beyond any representable exponent instead of overflowing
+            explicit_exponent = (explicit_exponent * 10 + digit as i64).min(1 << 32);
+            pos += 1;
+        }
+        if pos == exponent_start {
+            return Err(ParseError::new(ParseErrorKind::InvalidDigit, pos));
+        }
+
+        if negative_exponent {
+            scan.exponent -= explicit_exponent;
+        } else {
+            scan.exponent += explicit_exponent;
+        }
+    }
+
+    scan.len = pos;
+    Ok(scan)
+}
+
+#[inline(always)]
+fn ascii_digit(bytes: &[u8], pos: usize) -> Option<u64> {
+    match bytes.get(pos) {
+        Some(byte @ b'0'..=b'9') => Some((byte - b'0') as u64),
+        _ => None,
+    }
+}
+
+/// Checks for `inf`, `infinity` and `nan` spellings accepted by the standard float parser
+#[inline]
+fn is_non_finite_literal(bytes: &[u8]) -> bool {
+    bytes.len() >= 3
+        && (bytes[..3].eq_ignore_ascii_case(b"inf") || bytes[..3].eq_ignore_ascii_case(b"nan"))
+}
+
+/// Converts the scanned mantissa and exponent exactly, if both are representable in `T`.
+///
+/// When the mantissa and the power of ten are exact, a single multiplication or
+/// division rounds correctly to the nearest `T` (Clinger's fast path).
+#[inline]
+fn fast_path<T: DisplayableFloat>(scan: &DecimalScan) -> Option<T> {
+    if scan.truncated {
+        return None;
+    }
+
+    let mantissa = T::from(scan.mantissa)?;
+    if mantissa.to_u64() != Some(scan.mantissa) {
+        return None;
+    }
+
+    let power = POWERS_OF_TEN.get(scan.exponent.unsigned_abs() as usize)?;
+    let scale = T::from(*power)?;
+    if scale.to_u64() != Some(*power) {
+        return None;
+    }
+
+    let value = if scan.exponent < 0 {
+        mantissa / scale
+    } else {
+        mantissa * scale
+    };
+
+    Some(if scan.negative { -value } else { value })
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
+    /// Parses an ASCII decimal number from the start of a byte slice.
+    ///
+    /// Designed for wire protocols: there is no UTF-8 validation and no allocation,
+    /// and parsing stops at the first byte that can't continue the number (e.g. a
+    /// field delimiter). Returns the value along with the number of bytes consumed.
+    ///
+    /// The value is correctly rounded to the nearest `T`. Inputs of up to 19
+    /// significant digits whose value is exactly representable before scaling take
+    /// an exact fast path, the rest fall back to the standard library parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// // A FIX price field terminated by SOH
+    /// let (price, consumed) = ExtendedFloat::<f64>::parse_bytes(b"101.25\x01").unwrap();
+    /// assert_eq!(consumed, 6);
+    /// assert_eq!(price.format(), "101.25");
+    /// ```
+    pub fn parse_bytes(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let scan = scan_decimal(bytes)?;
+
+        let value = match fast_path::<T>(&scan) {
+            Some(value) => value,
+            None => {
+                // SAFETY: the scanned prefix consists of ASCII sign, digit, dot and
+                // exponent characters only, which is valid UTF-8
+                let text = unsafe { std::str::from_utf8_unchecked(&bytes[..scan.len]) };
+
+                // The scanned syntax is a subset of what the standard parser accepts,
+                // so this only fails if the two disagree
+                T::from_str_radix(text, 10)
+                    .map_err(|_| ParseError::new(ParseErrorKind::InvalidDigit, 0))?
+            }
+        };
+
+        if value.is_infinite() {
+            return Err(ParseError::new(
+                ParseErrorKind::ExponentOverflow,
+                scan.exponent_position.unwrap_or(0),
+            ));
+        }
+
+        Ok((unsafe { Self::new_unchecked(value) }, scan.len))
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> FromStr for TolerantFloat<T, Tol> {
+    type Err = ParseError;
+
+    /// Parses a decimal number like `-123.45` or `1.5e-3`.
+    ///
+    /// Leading or trailing whitespace, `NaN` and infinity are rejected,
+    /// as well as values too large to be represented by `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    /// use extended_float::types::extended_float::ParseErrorKind;
+    ///
+    /// let price: ExtendedFloat<f64> = "101.25".parse().unwrap();
+    /// assert_eq!(price, ExtendedFloat::new(101.25));
+    ///
+    /// let error = "12.3x".parse::<ExtendedFloat<f64>>().unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidDigit);
+    /// assert_eq!(error.position(), 4);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, consumed) = Self::parse_bytes(s.as_bytes())?;
+        if consumed < s.len() {
+            return Err(ParseError::new(ParseErrorKind::InvalidDigit, consumed));
+        }
+
+        Ok(value)
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TryFrom<&str> for TolerantFloat<T, Tol> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::test_utils::generator;
+    use crate::types::ExtendedFloat;
+
+    fn parse_error(s: &str) -> (ParseErrorKind, usize) {
+        let error = s.parse::<ExtendedFloat<f64>>().unwrap_err();
+        (error.kind(), error.position())
+    }
+
+    #[test]
+    fn test_parse_valid() {
+        let cases = [
+            ("0", 0.0),
+            ("-0", -0.0),
+            ("1", 1.0),
+            ("+1", 1.0),
+            ("-1", -1.0),
+            ("123.456", 123.456),
+            ("-123.456", -123.456),
+            ("0.1", 0.1),
+            (".5", 0.5),
+            ("5.", 5.0),
+            ("1e3", 1000.0),
+            ("1E3", 1000.0),
+            ("1.5e-3", 0.0015),
+            ("-2.5E+2", -250.0),
+            ("0000.00100", 0.001),
+            ("1e-400", 0.0),
+        ];
+
+        for (input, expected) in cases {
+            let parsed: ExtendedFloat<f64> = input.parse().unwrap();
+            assert_eq!(parsed.downgrade(), expected, "Failed for {}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_f32() {
+        let parsed: ExtendedFloat<f32> = "1.15".parse().unwrap();
+        assert_eq!(parsed.downgrade(), 1.15_f32);
+        assert_eq!(parsed.to_string(), "1.15");
+
+        let error = "1e39".parse::<ExtendedFloat<f32>>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::ExponentOverflow);
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for input in ["0.3", "101.25", "-0.0001", "1234567.89", "0.000000001"] {
+            let parsed: ExtendedFloat<f64> = input.parse().unwrap();
+            assert_eq!(parsed.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(parse_error(""), (ParseErrorKind::Empty, 0));
+        assert_eq!(parse_error("-"), (ParseErrorKind::Empty, 1));
+        assert_eq!(parse_error("."), (ParseErrorKind::Empty, 0));
+        assert_eq!(parse_error("+."), (ParseErrorKind::Empty, 1));
+    }
+
+    #[test]
+    fn test_parse_invalid_digit() {
+        assert_eq!(parse_error("abc"), (ParseErrorKind::InvalidDigit, 0));
+        assert_eq!(parse_error("12.3x"), (ParseErrorKind::InvalidDigit, 4));
+        assert_eq!(parse_error("1.2.3"), (ParseErrorKind::InvalidDigit, 3));
+        assert_eq!(parse_error(" 1"), (ParseErrorKind::InvalidDigit, 0));
+        assert_eq!(parse_error("1 "), (ParseErrorKind::InvalidDigit, 1));
+        assert_eq!(parse_error("--1"), (ParseErrorKind::InvalidDigit, 1));
+        assert_eq!(parse_error("1e"), (ParseErrorKind::InvalidDigit, 2));
+        assert_eq!(parse_error("1e+"), (ParseErrorKind::InvalidDigit, 3));
+        assert_eq!(parse_error("1e5.0"), (ParseErrorKind::InvalidDigit, 3));
+        assert_eq!(parse_error("-.x"), (ParseErrorKind::InvalidDigit, 2));
+    }
+
+    #[test]
+    fn test_parse_exponent_overflow() {
+        assert_eq!(parse_error("1e309"), (ParseErrorKind::ExponentOverflow, 1));
+        assert_eq!(
+            parse_error("-2.5e99999"),
+            (ParseErrorKind::ExponentOverflow, 4)
+        );
+        assert_eq!(
+            parse_error(&"9".repeat(400)),
+            (ParseErrorKind::ExponentOverflow, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_non_finite() {
+        assert_eq!(parse_error("NaN"), (ParseErrorKind::NonFinite, 0));
+        assert_eq!(parse_error("nan"), (ParseErrorKind::NonFinite, 0));
+        assert_eq!(parse_error("inf"), (ParseErrorKind::NonFinite, 0));
+        assert_eq!(parse_error("-inf"), (ParseErrorKind::NonFinite, 1));
+        assert_eq!(parse_error("+Infinity"), (ParseErrorKind::NonFinite, 1));
+    }
+
+    #[test]
+    fn test_parse_bytes_prefix() {
+        let (value, consumed) = ExtendedFloat::<f64>::parse_bytes(b"101.25\x0110=").unwrap();
+        assert_eq!(value.downgrade(), 101.25);
+        assert_eq!(consumed, 6);
+
+        let (value, consumed) = ExtendedFloat::<f64>::parse_bytes(b"-0.5|").unwrap();
+        assert_eq!(value.downgrade(), -0.5);
+        assert_eq!(consumed, 4);
+
+        let (value, consumed) = ExtendedFloat::<f64>::parse_bytes(b"42").unwrap();
+        assert_eq!(value.downgrade(), 42.0);
+        assert_eq!(consumed, 2);
+
+        // Bytes after the number don't need to be valid UTF-8
+        let (value, consumed) = ExtendedFloat::<f32>::parse_bytes(b"7.5\xff\xfe").unwrap();
+        assert_eq!(value.downgrade(), 7.5);
+        assert_eq!(consumed, 3);
+
+        let error = ExtendedFloat::<f64>::parse_bytes(b"\x01").unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidDigit);
+        assert_eq!(error.position(), 0);
+
+        let error = ExtendedFloat::<f64>::parse_bytes(b"1e|").unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidDigit);
+        assert_eq!(error.position(), 2);
+    }
+
+    #[test]
+    fn test_parse_bytes_format_round_trip() {
+        let fields: [&[u8]; 8] = [
+            b"0.3",
+            b"101.25",
+            b"1.15",
+            b"-0.00012345",
+            b"99999.99999",
+            b"123456789.123456",
+            b"0.000001",
+            b"12345678901234",
+        ];
+
+        for field in fields {
+            let (value, consumed) = ExtendedFloat::<f64>::parse_bytes(field).unwrap();
+            assert_eq!(consumed, field.len());
+            assert_eq!(value.format().as_bytes(), field);
+        }
+    }
+
+    #[test]
+    fn test_parse_bytes_correct_rounding() {
+        let mut next = generator(0x9E37_79B9_7F4A_7C15);
+
+        let mut buffer = Vec::with_capacity(64);
+        for _ in 0..100_000 {
+            buffer.clear();
+            if next().is_multiple_of(2) {
+                buffer.push(b'-');
+            }
+
+            let digits = 1 + (next() % 19) as usize;
+            let point = (next() % (digits as u64 + 1)) as usize;
+            for i in 0..digits {
+                if i == point {
+                    buffer.push(b'.');
+                }
+                buffer.push(b'0' + (next() % 10) as u8);
+            }
+            if next().is_multiple_of(3) {
+                let exponent = (next() % 60) as i64 - 30;
+                buffer.extend_from_slice(format!("e{}", exponent).as_bytes());
+            }
+
+            let text = std::str::from_utf8(&buffer).unwrap();
+            let (parsed, _) = ExtendedFloat::<f64>::parse_bytes(&buffer).unwrap();
+            assert_eq!(
+                parsed.downgrade().to_bits(),
+                text.parse::<f64>().unwrap().to_bits(),
+                "Failed for {}",
+                text
+            );
+
+            let expected = text.parse::<f32>().unwrap();
+            match ExtendedFloat::<f32>::parse_bytes(&buffer) {
+                Ok((parsed, _)) => {
+                    assert_eq!(
+                        parsed.downgrade().to_bits(),
+                        expected.to_bits(),
+                        "Failed for {}",
+                        text
+                    )
+                }
+                Err(error) => {
+                    assert!(expected.is_infinite(), "Failed for {}", text);
+                    assert_eq!(error.kind(), ParseErrorKind::ExponentOverflow);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_bytes_long_input() {
+        // More digits than fit into the fast path mantissa
+        let text = "1.00000000000000000000000000001";
+        let (parsed, consumed) = ExtendedFloat::<f64>::parse_bytes(text.as_bytes()).unwrap();
+        assert_eq!(consumed, text.len());
+        assert_eq!(parsed.downgrade(), text.parse::<f64>().unwrap());
+
+        let text = "123456789012345678901234567890";
+        let (parsed, _) = ExtendedFloat::<f64>::parse_bytes(text.as_bytes()).unwrap();
+        assert_eq!(parsed.downgrade(), text.parse::<f64>().unwrap());
+
+        let text = "0.0000000000000000000000000000123456789012345678901234567890";
+        let (parsed, _) = ExtendedFloat::<f64>::parse_bytes(text.as_bytes()).unwrap();
+        assert_eq!(parsed.downgrade(), text.parse::<f64>().unwrap());
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        assert_eq!(
+            ExtendedFloat::<f64>::try_from("42.5").unwrap(),
+            ExtendedFloat::new(42.5)
+        );
+        assert_eq!(
+            ExtendedFloat::<f64>::try_from("NaN").unwrap_err().kind(),
+            ParseErrorKind::NonFinite
+        );
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        assert_eq!(
+            "1.2.3"
+                .parse::<ExtendedFloat<f64>>()
+                .unwrap_err()
+                .to_string(),
+            "Invalid digit at position 3"
+        );
+        assert_eq!(
+            "inf".parse::<ExtendedFloat<f64>>().unwrap_err().to_string(),
+            "Cannot create ExtendedFloat from non-finite value at position 0"
+        );
+    }
+}
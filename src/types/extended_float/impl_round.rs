@@ -0,0 +1,347 @@
+use super::decimal::Decimal;
+use super::formatted::SliceWriter;
+use super::{RoundingMode, TolerantFloat};
+use crate::traits::{DisplayableFloat, Float, Tolerance};
+
+/// Space for a rounded decimal in exponent notation: a sign, up to 32 digits,
+/// a decimal point and an exponent
+const ROUND_BUFFER_LEN: usize = 48;
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
+    /// Rounds the value to `decimal_places` fractional digits according to `mode`.
+    ///
+    /// Rounding works on the decimal value as [`format`](Self::format) shows it, so
+    /// `2.675`, stored as `2.67499999999999982236431605997495353221893310546875`,
+    /// is a tie. The result is the float closest to the rounded decimal and formats
+    /// as exactly that decimal.
+    ///
+    /// Values within epsilon are zero, and values above the decimal precision threshold
+    /// are rounded as they are written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    /// use extended_float::types::extended_float::RoundingMode;
+    ///
+    /// let value = ExtendedFloat::new(2.675);
+    /// assert_eq!(value.round_dp(2, RoundingMode::HalfUp).format(), "2.68");
+    /// assert_eq!(value.round_dp(2, RoundingMode::HalfDown).format(), "2.67");
+    /// assert_eq!(value.round_dp(1, RoundingMode::Floor).format(), "2.6");
+    ///
+    /// let value = ExtendedFloat::new(-1.001);
+    /// assert_eq!(value.round_dp(2, RoundingMode::Up).format(), "-1.01");
+    /// assert_eq!(value.round_dp(2, RoundingMode::Ceiling).format(), "-1");
+    /// ```
+    pub fn round_dp(&self, decimal_places: u32, mode: RoundingMode) -> Self {
+        let value = self.downgrade();
+        if !value.is_finite() {
+            return *self;
+        }
+        if value.abs() <= <T as Float>::epsilon() {
+            return Self::new(T::zero());
+        }
+
+        let mut decimal = shown_decimal(value);
+        decimal.round_fraction(decimal_places as usize, mode);
+
+        let mut buffer = [0u8; ROUND_BUFFER_LEN];
+        let mut writer = SliceWriter::new(&mut buffer);
+        decimal
+            .write_exp(&mut writer, 1, 0, false)
+            .expect("Rounded decimal fits into the buffer");
+
+        // Rounding changes the value by less than a unit of its last kept digit,
+        // so the result stays finite
+        let (rounded, _) = Self::parse_bytes(writer.as_str().as_bytes())
+            .expect("Rounded decimal is a valid finite number");
+        rounded
+    }
+
+    /// Returns the largest integer less than or equal to the value.
+    ///
+    /// Values that [`format`](Self::format) shows as an integer are that integer, so
+    /// artifacts like `2.9999999999999996` don't drop a whole unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// assert_eq!(ExtendedFloat::new(2.9999999999999996).floor().downgrade(), 3.0);
+    /// assert_eq!(ExtendedFloat::new(2.9).floor().downgrade(), 2.0);
+    /// assert_eq!(ExtendedFloat::new(-2.1).floor().downgrade(), -3.0);
+    /// ```
+    #[inline]
+    pub fn floor(&self) -> Self {
+        self.round_integer(RoundingMode::Floor)
+    }
+
+    /// Returns the smallest integer greater than or equal to the value.
+    ///
+    /// Values that [`format`](Self::format) shows as an integer are that integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// assert_eq!(ExtendedFloat::new(3.0000000000000004).ceil().downgrade(), 3.0);
+    /// assert_eq!(ExtendedFloat::new(2.1).ceil().downgrade(), 3.0);
+    /// ```
+    #[inline]
+    pub fn ceil(&self) -> Self {
+        self.round_integer(RoundingMode::Ceiling)
+    }
+
+    /// Returns the integer part of the value, rounding toward zero.
+    ///
+    /// Values that [`format`](Self::format) shows as an integer are that integer.
+    #[inline]
+    pub fn trunc(&self) -> Self {
+        self.round_integer(RoundingMode::Down)
+    }
+
+    /// Returns the nearest integer, rounding half-way cases away from zero like
+    /// [`f64::round`].
+    ///
+    /// Ties are decided on the shown decimal, so `2.4999999999999996`, shown as `2.5`,
+    /// rounds to `3`.
+    #[inline]
+    pub fn round(&self) -> Self {
+        self.round_integer(RoundingMode::HalfUp)
+    }
+
+    /// Returns the fractional part of the value, with the sign of the value.
+    ///
+    /// The result is zero for values that [`format`](Self::format) shows as an integer,
+    /// and otherwise formats as the shown fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// assert_eq!(ExtendedFloat::new(2.9999999999999996).fract().downgrade(), 0.0);
+    /// assert_eq!(ExtendedFloat::new(1.1).fract().downgrade(), 0.1);
+    /// assert_eq!(ExtendedFloat::new(-1.25).fract().downgrade(), -0.25);
+    /// ```
+    pub fn fract(&self) -> Self {
+        let value = self.downgrade();
+        if !value.is_finite() {
+            return *self;
+        }
+        if value.abs() <= <T as Float>::epsilon() {
+            return Self::new(T::zero());
+        }
+
+        let fraction_digits = shown_decimal(value).fraction_digits();
+        if fraction_digits == 0 {
+            return Self::new(T::zero());
+        }
+        Self::new(value - self.trunc().downgrade())
+            .round_dp(fraction_digits as u32, RoundingMode::HalfEven)
+    }
+
+    /// Returns `true` if [`format`](Self::format) shows the value as an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// assert!(ExtendedFloat::new(2.9999999999999996).is_integer());
+    /// assert!(ExtendedFloat::new(1e-13).is_integer());
+    /// assert!(!ExtendedFloat::new(2.5).is_integer());
+    /// ```
+    pub fn is_integer(&self) -> bool {
+        let value = self.downgrade();
+        if !value.is_finite() {
+            return false;
+        }
+        value.abs() <= <T as Float>::epsilon() || shown_decimal(value).fraction_digits() == 0
+    }
+
+    fn round_integer(&self, mode: RoundingMode) -> Self {
+        let value = self.downgrade();
+        if value.is_finite() && value.abs() <= <T as Float>::epsilon() {
+            return Self::new(T::zero());
+        }
+        self.round_dp(0, mode)
+    }
+}
+
+/// Decimal digits of a finite value as [`ExtendedFloat::format`] shows them
+#[inline]
+fn shown_decimal<T: DisplayableFloat>(value: T) -> Decimal {
+    match value.abs() > T::decimal_precision() {
+        true => Decimal::from_display(value),
+        false => Decimal::from_float(value, value.precision()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::test_utils::generator;
+    use crate::types::ExtendedFloat;
+    use crate::types::extended_float::FormatSpec;
+
+    const MODES: [RoundingMode; 7] = [
+        RoundingMode::HalfUp,
+        RoundingMode::HalfDown,
+        RoundingMode::HalfEven,
+        RoundingMode::Up,
+        RoundingMode::Down,
+        RoundingMode::Ceiling,
+        RoundingMode::Floor,
+    ];
+
+    #[test]
+    fn test_round_dp_modes() {
+        let cases = [
+            // value, places, HalfUp, HalfDown, HalfEven, Up, Down, Ceiling, Floor
+            (2.675, 2, [2.68, 2.67, 2.68, 2.68, 2.67, 2.68, 2.67]),
+            (2.665, 2, [2.67, 2.66, 2.66, 2.67, 2.66, 2.67, 2.66]),
+            (-2.675, 2, [-2.68, -2.67, -2.68, -2.68, -2.67, -2.67, -2.68]),
+            (1.005, 2, [1.01, 1.0, 1.0, 1.01, 1.0, 1.01, 1.0]),
+            (2.5, 0, [3.0, 2.0, 2.0, 3.0, 2.0, 3.0, 2.0]),
+            (-0.5, 0, [-1.0, 0.0, 0.0, -1.0, 0.0, 0.0, -1.0]),
+            (0.001, 2, [0.0, 0.0, 0.0, 0.01, 0.0, 0.01, 0.0]),
+            (9.999, 2, [10.0, 10.0, 10.0, 10.0, 9.99, 10.0, 9.99]),
+            (1.25, 5, [1.25, 1.25, 1.25, 1.25, 1.25, 1.25, 1.25]),
+        ];
+
+        for (value, places, expected) in cases {
+            for (mode, expected) in MODES.into_iter().zip(expected) {
+                let rounded = ExtendedFloat::new(value).round_dp(places, mode);
+                assert_eq!(rounded.downgrade(), expected, "{} {:?}", value, mode);
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_dp_visible_decimal() {
+        // Artifacts don't count as digits to round
+        let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+        assert_eq!(value.round_dp(1, RoundingMode::Up).downgrade(), 0.3);
+        assert_eq!(value.round_dp(1, RoundingMode::Ceiling).downgrade(), 0.3);
+
+        let value = ExtendedFloat::new(0.7) - ExtendedFloat::new(0.4);
+        assert_eq!(value.round_dp(1, RoundingMode::Down).downgrade(), 0.3);
+        assert_eq!(value.round_dp(1, RoundingMode::Floor).downgrade(), 0.3);
+
+        // Values within epsilon are zero
+        let value = ExtendedFloat::new(1e-13);
+        assert_eq!(value.round_dp(2, RoundingMode::Up).downgrade(), 0.0);
+        assert_eq!(value.round_dp(2, RoundingMode::Up).format(), "0");
+        let rounded = ExtendedFloat::new(-1e-13_f64).round_dp(2, RoundingMode::Floor);
+        assert_eq!(rounded.downgrade(), 0.0);
+        assert!(rounded.downgrade().is_sign_positive());
+
+        // Big values have no fractional digits to round
+        let value = ExtendedFloat::new(1e20);
+        assert_eq!(value.round_dp(2, RoundingMode::Up).downgrade(), 1e20);
+    }
+
+    #[test]
+    fn test_round_dp_f32() {
+        let value = ExtendedFloat::new(2.675_f32);
+        assert_eq!(value.round_dp(2, RoundingMode::HalfUp).downgrade(), 2.68);
+        assert_eq!(value.round_dp(2, RoundingMode::HalfEven).downgrade(), 2.68);
+        assert_eq!(value.round_dp(2, RoundingMode::Down).downgrade(), 2.67);
+    }
+
+    #[test]
+    fn test_integer_rounding() {
+        let cases = [
+            // value, floor, ceil, trunc, round
+            (2.9999999999999996, 3.0, 3.0, 3.0, 3.0),
+            (3.0000000000000004, 3.0, 3.0, 3.0, 3.0),
+            (-2.9999999999999996, -3.0, -3.0, -3.0, -3.0),
+            (2.9, 2.0, 3.0, 2.0, 3.0),
+            (-2.9, -3.0, -2.0, -2.0, -3.0),
+            (2.5, 2.0, 3.0, 2.0, 3.0),
+            (-2.5, -3.0, -2.0, -2.0, -3.0),
+            (2.4999999999999996, 2.0, 3.0, 2.0, 3.0),
+            (0.3, 0.0, 1.0, 0.0, 0.0),
+            (-0.3, -1.0, 0.0, 0.0, 0.0),
+            (1e-13, 0.0, 0.0, 0.0, 0.0),
+            (-1e-13, 0.0, 0.0, 0.0, 0.0),
+            (1e20, 1e20, 1e20, 1e20, 1e20),
+        ];
+
+        for (value, floor, ceil, trunc, round) in cases {
+            let value = ExtendedFloat::new(value);
+            assert_eq!(value.floor().downgrade(), floor, "floor {}", value);
+            assert_eq!(value.ceil().downgrade(), ceil, "ceil {}", value);
+            assert_eq!(value.trunc().downgrade(), trunc, "trunc {}", value);
+            assert_eq!(value.round().downgrade(), round, "round {}", value);
+        }
+
+        // Accumulated error
+        let value = ExtendedFloat::new(0.1) * ExtendedFloat::new(3.0) * ExtendedFloat::new(10.0);
+        assert_eq!(value.floor().downgrade(), 3.0);
+
+        // Results are never negative zero
+        assert!(ExtendedFloat::new(-0.3).ceil().is_sign_positive());
+        assert!(ExtendedFloat::new(-1e-13).floor().is_sign_positive());
+    }
+
+    #[test]
+    fn test_fract_and_is_integer() {
+        let cases = [
+            (2.9999999999999996, 0.0, true),
+            (1.1, 0.1, false),
+            (-1.25, -0.25, false),
+            (1234.5678, 0.5678, false),
+            (0.30000000000000004, 0.3, false),
+            (5.0, 0.0, true),
+            (1e-13, 0.0, true),
+            (1e20, 0.0, true),
+        ];
+
+        for (value, fract, is_integer) in cases {
+            let value = ExtendedFloat::new(value);
+            assert_eq!(value.fract().downgrade(), fract, "fract {}", value);
+            assert_eq!(value.is_integer(), is_integer, "is_integer {}", value);
+        }
+    }
+
+    #[test]
+    fn test_integer_rounding_f32() {
+        let value = ExtendedFloat::new(2.9999998_f32);
+        assert!(value.is_integer());
+        assert_eq!(value.floor().downgrade(), 3.0);
+        assert_eq!(ExtendedFloat::new(2.5_f32).floor().downgrade(), 2.0);
+        assert_eq!(ExtendedFloat::new(1.1_f32).fract().downgrade(), 0.1);
+    }
+
+    #[test]
+    fn test_round_dp_matches_format() {
+        let mut next = generator(0x853C_49E6_748F_EA9B);
+
+        for _ in 0..50_000 {
+            let value = (next() % 20_000_000) as f64 / 10f64.powi((next() % 8) as i32) - 1000.0;
+            let value = ExtendedFloat::new(value);
+            let places = (next() % 6) as u32;
+
+            for mode in MODES {
+                let rounded = value.round_dp(places, mode);
+                let spec = FormatSpec::new()
+                    .max_fraction_digits(places as usize)
+                    .rounding(mode);
+                assert_eq!(
+                    rounded.format(),
+                    value.format_with(&spec),
+                    "{} {} {:?}",
+                    value,
+                    places,
+                    mode
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,464 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use num_traits::Float as NumFloat;
+
+use super::decimal::Decimal;
+use super::{ConversionError, RoundingMode, TolerantFloat};
+use crate::traits::{DisplayableFloat, Float, Tolerance};
+
+/// Error type for snapping a value to multiples of an invalid step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepError {
+    /// Step is zero
+    ZeroStep,
+    /// Step is negative
+    NegativeStep,
+    /// Step or result is NaN or infinite
+    Conversion(ConversionError),
+}
+
+impl fmt::Display for StepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StepError::ZeroStep => write!(f, "Step must not be zero"),
+            StepError::NegativeStep => write!(f, "Step must be positive"),
+            StepError::Conversion(error) => fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+impl std::error::Error for StepError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StepError::Conversion(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ConversionError> for StepError {
+    fn from(error: ConversionError) -> Self {
+        StepError::Conversion(error)
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
+    /// Rounds the value to the nearest multiple of `step`, ties away from zero.
+    ///
+    /// The number of steps is found with a tolerance for floating-point errors and the
+    /// result is built from the decimal digits of `step`, so it's exactly the float
+    /// closest to the decimal multiple.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `step` is not positive or not finite, or if the result
+    /// is not finite. For non-panicking versions, use `checked_round_by_step` or
+    /// `try_round_by_step`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let value = ExtendedFloat::new(1.15);
+    /// assert_eq!(value.round_by_step(0.05).downgrade(), 1.15);
+    /// assert_eq!(ExtendedFloat::new(1.174).round_by_step(0.05).downgrade(), 1.15);
+    /// assert_eq!(ExtendedFloat::new(1.175).round_by_step(0.05).downgrade(), 1.2);
+    /// ```
+    #[inline]
+    pub fn round_by_step(&self, step: T) -> Self {
+        unwrap_step(self.try_round_by_step(step))
+    }
+
+    /// Rounds the value down to a multiple of `step`.
+    ///
+    /// Values within the floating-point error of the next multiple snap to it, so
+    /// `1.15` stays `1.15` with a step of `0.05` although it's stored below it.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `step` is not positive or not finite, or if the result
+    /// is not finite. For non-panicking versions, use `checked_floor_by_step` or
+    /// `try_floor_by_step`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// assert_eq!(ExtendedFloat::new(1.15).floor_by_step(0.05).downgrade(), 1.15);
+    /// assert_eq!(ExtendedFloat::new(1.199).floor_by_step(0.05).downgrade(), 1.15);
+    /// assert_eq!(ExtendedFloat::new(-1.01).floor_by_step(0.05).downgrade(), -1.05);
+    /// ```
+    #[inline]
+    pub fn floor_by_step(&self, step: T) -> Self {
+        unwrap_step(self.try_floor_by_step(step))
+    }
+
+    /// Rounds the value up to a multiple of `step`.
+    ///
+    /// Values within the floating-point error of the previous multiple snap to it.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `step` is not positive or not finite, or if the result
+    /// is not finite. For non-panicking versions, use `checked_ceil_by_step` or
+    /// `try_ceil_by_step`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let value = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+    /// assert_eq!(value.ceil_by_step(0.1).downgrade(), 0.3);
+    /// assert_eq!(ExtendedFloat::new(1.0001).ceil_by_step(0.0001).downgrade(), 1.0001);
+    /// assert_eq!(ExtendedFloat::new(1.00011).ceil_by_step(0.0001).downgrade(), 1.0002);
+    /// ```
+    #[inline]
+    pub fn ceil_by_step(&self, step: T) -> Self {
+        unwrap_step(self.try_ceil_by_step(step))
+    }
+
+    /// Rounds the value to the nearest multiple of `step`, returning None if the step
+    /// is not positive or not finite, or if the result is not finite
+    #[inline]
+    pub fn checked_round_by_step(&self, step: T) -> Option<Self> {
+        self.try_round_by_step(step).ok()
+    }
+
+    /// Rounds the value down to a multiple of `step`, returning None if the step
+    /// is not positive or not finite, or if the result is not finite
+    #[inline]
+    pub fn checked_floor_by_step(&self, step: T) -> Option<Self> {
+        self.try_floor_by_step(step).ok()
+    }
+
+    /// Rounds the value up to a multiple of `step`, returning None if the step
+    /// is not positive or not finite, or if the result is not finite
+    #[inline]
+    pub fn checked_ceil_by_step(&self, step: T) -> Option<Self> {
+        self.try_ceil_by_step(step).ok()
+    }
+
+    /// Similar to checked_round_by_step but returns a Result with detailed error information
+    #[inline]
+    pub fn try_round_by_step(&self, step: T) -> Result<Self, StepError> {
+        self.snap_to_step(step, RoundingMode::HalfUp)
+    }
+
+    /// Similar to checked_floor_by_step but returns a Result with detailed error information
+    #[inline]
+    pub fn try_floor_by_step(&self, step: T) -> Result<Self, StepError> {
+        self.snap_to_step(step, RoundingMode::Floor)
+    }
+
+    /// Similar to checked_ceil_by_step but returns a Result with detailed error information
+    #[inline]
+    pub fn try_ceil_by_step(&self, step: T) -> Result<Self, StepError> {
+        self.snap_to_step(step, RoundingMode::Ceiling)
+    }
+
+    /// Snaps the value to a multiple of `step` according to `mode`.
+    ///
+    /// The multiple is computed from the digits of `step` as an exact integer product
+    /// divided by a power of ten, falling back to a plain product when those don't fit
+    /// into the mantissa.
+    pub(crate) fn snap_to_step(&self, step: T, mode: RoundingMode) -> Result<Self, StepError> {
+        let steps = self.steps_by(step, mode)?;
+        Ok(Self::from_steps(steps, step)?)
+    }
+
+    /// Creates the value `steps * step` for an integral number of steps, without drift
+    #[inline]
+    pub(crate) fn from_steps(steps: T, step: T) -> Result<Self, ConversionError> {
+        Self::try_from_value(multiply_step(steps, step))
+    }
+
+    /// Returns the integral number of steps closest to the value according to `mode`.
+    ///
+    /// The quotient `value / step` is compared with the neighbouring integers (and the
+    /// midpoint between them for the half modes) with a tolerance of half a unit of its
+    /// last meaningful digit, but at least epsilon and at most a sixteenth of a step.
+    pub(crate) fn steps_by(&self, step: T, mode: RoundingMode) -> Result<T, StepError> {
+        let step = Self::try_from_value(step)?.downgrade();
+        if step.is_zero() {
+            return Err(StepError::ZeroStep);
+        }
+        if step < T::zero() {
+            return Err(StepError::NegativeStep);
+        }
+
+        let quotient = Self::try_from_value(self.downgrade() / step)?.downgrade();
+        Ok(snap_quotient(quotient, mode))
+    }
+}
+
+/// Unwraps the result of a step operation, panicking like the operators do
+#[inline]
+fn unwrap_step<T: DisplayableFloat, Tol: Tolerance<T>>(
+    result: Result<TolerantFloat<T, Tol>, StepError>,
+) -> TolerantFloat<T, Tol> {
+    match result {
+        Ok(value) => value,
+        Err(StepError::Conversion(error)) => error.panic(),
+        Err(error) => panic!("{}", error),
+    }
+}
+
+/// Rounds a finite quotient to an integer according to `mode`, treating values within
+/// the floating-point error of an integer or a midpoint as exactly that.
+///
+/// The tolerance is half a unit of the last meaningful digit of the quotient, but at
+/// least epsilon and at most a sixteenth of a step. For huge quotients the digit
+/// tolerance reaches half a step, a fraction that large is part of the value, and
+/// snapping it would make ceil return less than the value and floor more.
+fn snap_quotient<T: DisplayableFloat>(quotient: T, mode: RoundingMode) -> T {
+    let half = T::from(0.5).unwrap();
+    let max_tolerance = T::from(0.0625).unwrap();
+    let tolerance = <T as Float>::epsilon()
+        .max(half / pow10::<T>(quotient.precision()))
+        .min(max_tolerance);
+    let near = |a: T, b: T| (a - b).abs() <= tolerance;
+
+    let floor = quotient.floor();
+    let ceil = floor + T::one();
+    if near(quotient, floor) {
+        return floor;
+    }
+    if near(quotient, ceil) {
+        return ceil;
+    }
+
+    // Rounding modes work on magnitudes: the integer toward zero is kept, and the
+    // fraction beyond it is compared with a half
+    let negative = quotient < T::zero();
+    let (kept, away) = match negative {
+        true => (ceil, floor),
+        false => (floor, ceil),
+    };
+    let midpoint = floor + half;
+    let half_ordering = match near(quotient, midpoint) {
+        true => Ordering::Equal,
+        false => (quotient - kept).abs().partial_cmp(&half).unwrap(),
+    };
+    let kept_odd = kept % (T::one() + T::one()) != T::zero();
+
+    match mode.rounds_up(negative, half_ordering, kept_odd) {
+        true => away,
+        false => kept,
+    }
+}
+
+/// Multiplies an integral number of steps by `step` without introducing drift.
+///
+/// With `d` fractional digits in the shortest representation of `step`, the result is
+/// `(steps * step * 10^d) / 10^d`, where the numerator and denominator are exact
+/// integers, so the single division rounds correctly to the decimal multiple.
+fn multiply_step<T: DisplayableFloat>(steps: T, step: T) -> T {
+    let fraction_digits = Decimal::from_shortest(step).fraction_digits();
+    // Largest integer below which all integers are exactly representable
+    let max_exact = (T::one() + T::one()) / <T as NumFloat>::epsilon();
+
+    let scale = pow10::<T>(fraction_digits);
+    let scaled_step = (step * scale).round();
+    let numerator = steps * scaled_step;
+    if scale > max_exact || numerator.abs() > max_exact {
+        return steps * step;
+    }
+
+    numerator / scale
+}
+
+/// Returns `10^exponent`, exact for powers that fit into the mantissa
+#[inline]
+fn pow10<T: DisplayableFloat>(exponent: usize) -> T {
+    let ten = T::from(10).unwrap();
+    (0..exponent).fold(T::one(), |power, _| power * ten)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::types::ExtendedFloat;
+
+    #[test]
+    fn test_round_by_step() {
+        let round =
+            |value: f64, step: f64| ExtendedFloat::new(value).round_by_step(step).downgrade();
+
+        assert_eq!(round(1.15, 0.05), 1.15);
+        assert_eq!(round(1.16, 0.05), 1.15);
+        assert_eq!(round(1.174, 0.05), 1.15);
+        assert_eq!(round(1.175, 0.05), 1.2);
+        assert_eq!(round(1.125, 0.05), 1.15);
+        assert_eq!(round(-1.125, 0.05), -1.15);
+        assert_eq!(round(-1.124, 0.05), -1.1);
+        assert_eq!(round(0.0, 0.05), 0.0);
+        assert_eq!(round(12345.678, 0.0001), 12345.678);
+        assert_eq!(round(12345.67849, 0.001), 12345.678);
+        assert_eq!(round(7.0, 2.5), 7.5);
+        assert_eq!(round(1234.0, 100.0), 1200.0);
+        assert_eq!(round(0.3, 0.1), 0.3);
+    }
+
+    #[test]
+    fn test_floor_by_step() {
+        let floor =
+            |value: f64, step: f64| ExtendedFloat::new(value).floor_by_step(step).downgrade();
+
+        assert_eq!(floor(1.15, 0.05), 1.15);
+        assert_eq!(floor(1.199, 0.05), 1.15);
+        assert_eq!(floor(-1.01, 0.05), -1.05);
+        assert_eq!(floor(-1.05, 0.05), -1.05);
+        assert_eq!(floor(0.7 - 0.4, 0.1), 0.3);
+        assert_eq!(floor(100.00999, 0.01), 100.0);
+        assert_eq!(floor(0.0299999, 0.01), 0.02);
+    }
+
+    #[test]
+    fn test_ceil_by_step() {
+        let ceil = |value: f64, step: f64| ExtendedFloat::new(value).ceil_by_step(step).downgrade();
+
+        assert_eq!(ceil(0.1 + 0.2, 0.1), 0.3);
+        assert_eq!(ceil(1.15, 0.05), 1.15);
+        assert_eq!(ceil(1.1501, 0.05), 1.2);
+        assert_eq!(ceil(-1.19, 0.05), -1.15);
+        assert_eq!(ceil(1.0001, 0.0001), 1.0001);
+        assert_eq!(ceil(1.00011, 0.0001), 1.0002);
+    }
+
+    #[test]
+    fn test_step_large_magnitudes() {
+        let value = ExtendedFloat::new(999999999999999.5);
+        assert_eq!(value.ceil_by_step(1.0).downgrade(), 1e15);
+        assert_eq!(value.floor_by_step(1.0).downgrade(), 999999999999999.0);
+        assert_eq!(value.round_by_step(1.0).downgrade(), 1e15);
+
+        let value = ExtendedFloat::new(1e15 + 0.25);
+        assert_eq!(value.ceil_by_step(0.5).downgrade(), 1e15 + 0.5);
+        assert_eq!(value.floor_by_step(0.5).downgrade(), 1e15);
+
+        let value = ExtendedFloat::new(10000.004_f32);
+        assert_eq!(value.ceil_by_step(0.01).downgrade(), 10000.01);
+        assert_eq!(value.floor_by_step(0.01).downgrade(), 10000.0);
+
+        // Quotients with a quarter, half or three quarters of a step, all exact
+        for step in [0.25, 0.5, 1.0, 2.0] {
+            for magnitude in 1..=14 {
+                for fraction in [0.25, 0.5, 0.75] {
+                    let value = (10f64.powi(magnitude) + fraction) * step;
+                    let extended = ExtendedFloat::new(value);
+                    let ceil = extended.ceil_by_step(step).downgrade();
+                    let floor = extended.floor_by_step(step).downgrade();
+                    assert!(ceil >= value, "{} {}", value, step);
+                    assert!(floor <= value, "{} {}", value, step);
+                    assert_eq!(ceil - floor, step, "{} {}", value, step);
+                }
+            }
+        }
+
+        for step in [0.25_f32, 0.5, 1.0, 2.0] {
+            for magnitude in 1..=5 {
+                for fraction in [0.25, 0.5, 0.75] {
+                    let value = (10f32.powi(magnitude) + fraction) * step;
+                    let extended = ExtendedFloat::new(value);
+                    let ceil = extended.ceil_by_step(step).downgrade();
+                    let floor = extended.floor_by_step(step).downgrade();
+                    assert!(ceil >= value, "{} {}", value, step);
+                    assert!(floor <= value, "{} {}", value, step);
+                    assert_eq!(ceil - floor, step, "{} {}", value, step);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_step_no_drift() {
+        // Every multiple must come out as the float closest to its decimal value
+        let step = 0.05;
+        for i in -2000..2000 {
+            let expected: f64 = format!("{}", i as f64 * 5.0 / 100.0).parse().unwrap();
+            let value = ExtendedFloat::new(i as f64 * step);
+            assert_eq!(value.round_by_step(step).downgrade(), expected, "{}", i);
+            assert_eq!(value.floor_by_step(step).downgrade(), expected, "{}", i);
+            assert_eq!(value.ceil_by_step(step).downgrade(), expected, "{}", i);
+        }
+
+        let step = 0.0001;
+        let mut value = ExtendedFloat::new(0.0);
+        for i in 1..=10000 {
+            value += ExtendedFloat::new(step);
+            let expected = i as f64 / 10000.0;
+            assert_eq!(value.round_by_step(step).downgrade(), expected, "{}", i);
+        }
+    }
+
+    #[test]
+    fn test_step_f32() {
+        let value = ExtendedFloat::new(1.15_f32);
+        assert_eq!(value.round_by_step(0.05).downgrade(), 1.15);
+        assert_eq!(value.floor_by_step(0.05).downgrade(), 1.15);
+        assert_eq!(value.ceil_by_step(0.05).downgrade(), 1.15);
+        assert_eq!(
+            ExtendedFloat::new(2.37_f32).floor_by_step(0.25).downgrade(),
+            2.25
+        );
+    }
+
+    #[test]
+    fn test_checked_step() {
+        let value = ExtendedFloat::new(1.15);
+
+        assert_eq!(
+            value.checked_round_by_step(0.05),
+            Some(ExtendedFloat::new(1.15))
+        );
+        assert_eq!(value.checked_round_by_step(0.0), None);
+        assert_eq!(value.checked_floor_by_step(-0.05), None);
+        assert_eq!(value.checked_ceil_by_step(f64::NAN), None);
+
+        assert_eq!(value.try_round_by_step(0.0), Err(StepError::ZeroStep));
+        assert_eq!(value.try_round_by_step(-0.0), Err(StepError::ZeroStep));
+        assert_eq!(value.try_floor_by_step(-0.05), Err(StepError::NegativeStep));
+        assert_eq!(
+            value.try_ceil_by_step(f64::NAN),
+            Err(StepError::Conversion(ConversionError::NaN))
+        );
+        assert_eq!(
+            value.try_ceil_by_step(f64::INFINITY),
+            Err(StepError::Conversion(ConversionError::Infinite))
+        );
+
+        // The number of steps overflows
+        assert_eq!(
+            ExtendedFloat::new(f64::MAX).try_round_by_step(1e-10),
+            Err(StepError::Conversion(ConversionError::Infinite))
+        );
+    }
+
+    #[test]
+    fn test_step_error_display() {
+        assert_eq!(StepError::ZeroStep.to_string(), "Step must not be zero");
+        assert_eq!(StepError::NegativeStep.to_string(), "Step must be positive");
+        assert_eq!(
+            StepError::Conversion(ConversionError::NaN).to_string(),
+            "Cannot create ExtendedFloat from NaN"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Step must not be zero")]
+    fn test_round_by_step_panics_on_zero_step() {
+        ExtendedFloat::new(1.0).round_by_step(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Step must be positive")]
+    fn test_floor_by_step_panics_on_negative_step() {
+        ExtendedFloat::new(1.0).floor_by_step(-1.0);
+    }
+}
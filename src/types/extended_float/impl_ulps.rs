@@ -0,0 +1,238 @@
+use super::TolerantFloat;
+use crate::traits::{DisplayableFloat, Tolerance};
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
+    /// Returns the number of floats between `a` and `b`, counting `b` but not `a`.
+    ///
+    /// The distance in units in the last place (ULPs) doesn't depend on the magnitude,
+    /// both zeros are at distance zero and values of opposite signs count the floats
+    /// on both sides of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let sum = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+    /// assert_eq!(ExtendedFloat::ulps_between(sum, ExtendedFloat::new(0.3)), 1);
+    /// assert_eq!(ExtendedFloat::ulps_between(ExtendedFloat::new(0.0), ExtendedFloat::new(-0.0)), 0);
+    /// ```
+    #[inline]
+    pub fn ulps_between(a: Self, b: Self) -> u64 {
+        a.downgrade()
+            .ulp_index()
+            .abs_diff(b.downgrade().ulp_index())
+    }
+
+    /// Returns true if the values are at most `ulps` floats apart, see
+    /// [`ulps_between`](Self::ulps_between).
+    ///
+    /// Unlike `==`, the check doesn't depend on the tolerance or the magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// let sum = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+    /// assert!(sum.eq_within_ulps(ExtendedFloat::new(0.3), 1));
+    /// assert!(!sum.eq_within_ulps(ExtendedFloat::new(0.3), 0));
+    /// assert!(!ExtendedFloat::new(1e-14).eq_within_ulps(ExtendedFloat::new(2e-14), 1000));
+    /// ```
+    #[inline]
+    pub fn eq_within_ulps(&self, other: Self, ulps: u64) -> bool {
+        Self::ulps_between(*self, other) <= ulps
+    }
+
+    /// Returns the smallest float greater than the value.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value is the largest finite float.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// assert_eq!(ExtendedFloat::new(1.0).next_up().downgrade(), 1.0 + f64::EPSILON);
+    /// assert_eq!(ExtendedFloat::new(-0.0).next_up().downgrade(), f64::from_bits(1));
+    /// ```
+    #[inline]
+    pub fn next_up(&self) -> Self {
+        Self::new(T::from_ulp_index(self.downgrade().ulp_index() + 1))
+    }
+
+    /// Returns the largest float less than the value.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value is the smallest finite float.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// assert_eq!(ExtendedFloat::new(1.0).next_down().downgrade(), 1.0 - f64::EPSILON / 2.0);
+    /// assert_eq!(ExtendedFloat::new(0.0).next_down().downgrade(), -f64::from_bits(1));
+    /// ```
+    #[inline]
+    pub fn next_down(&self) -> Self {
+        Self::new(T::from_ulp_index(self.downgrade().ulp_index() - 1))
+    }
+
+    /// Returns the unit in the last place: the gap between the magnitude of the value
+    /// and the next float away from zero.
+    ///
+    /// For the largest finite float, which has no finite float above it, this is the
+    /// gap to the float below.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    ///
+    /// assert_eq!(ExtendedFloat::new(1.0).ulp().downgrade(), f64::EPSILON);
+    /// assert_eq!(ExtendedFloat::new(-1e12).ulp().downgrade(), 1.0 / 8192.0);
+    /// assert_eq!(ExtendedFloat::new(0.0).ulp().downgrade(), f64::from_bits(1));
+    /// ```
+    pub fn ulp(&self) -> Self {
+        let magnitude = self.downgrade().abs();
+        let index = magnitude.ulp_index();
+        let above = T::from_ulp_index(index + 1);
+        match above.is_finite() {
+            true => Self::new(above - magnitude),
+            false => Self::new(magnitude - T::from_ulp_index(index - 1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::types::ExtendedFloat;
+
+    #[test]
+    fn test_ulps_between() {
+        let one = ExtendedFloat::new(1.0);
+        assert_eq!(ExtendedFloat::ulps_between(one, one), 0);
+        assert_eq!(ExtendedFloat::ulps_between(one, one.next_up()), 1);
+        assert_eq!(
+            ExtendedFloat::ulps_between(one.next_up(), one.next_down()),
+            2
+        );
+
+        // Magnitude-independent, unlike the epsilon comparison
+        for value in [1e-300, 1e-14, 1.0, 1e12, 1e300] {
+            let value = ExtendedFloat::new(value);
+            let shifted = value.next_up().next_up().next_up().next_up();
+            assert!(value.eq_within_ulps(shifted, 4), "{}", value);
+            assert!(!value.eq_within_ulps(shifted, 3), "{}", value);
+        }
+
+        let smallest = ExtendedFloat::new(f64::from_bits(1));
+        assert_eq!(ExtendedFloat::ulps_between(smallest, -smallest), 2);
+        assert_eq!(
+            ExtendedFloat::ulps_between(
+                ExtendedFloat::new(f64::MAX),
+                ExtendedFloat::new(-f64::MAX)
+            ),
+            2 * 0x7FEF_FFFF_FFFF_FFFF
+        );
+
+        let one = ExtendedFloat::new(1.0_f32);
+        assert_eq!(
+            ExtendedFloat::ulps_between(one, ExtendedFloat::new(1.0 + 3.0 * f32::EPSILON)),
+            3
+        );
+    }
+
+    #[test]
+    fn test_next_up_down() {
+        for value in [
+            -1e300,
+            -1.5,
+            -f64::MIN_POSITIVE,
+            0.0,
+            1e-320,
+            0.1,
+            1.0,
+            4e15,
+        ] {
+            let value = ExtendedFloat::new(value);
+            let up = value.next_up();
+            let down = value.next_down();
+            assert!(up.downgrade() > value.downgrade(), "{}", value);
+            assert!(down.downgrade() < value.downgrade(), "{}", value);
+            assert_eq!(up.next_down().downgrade(), value.downgrade());
+            assert_eq!(down.next_up().downgrade(), value.downgrade());
+        }
+
+        assert_eq!(
+            ExtendedFloat::new(-0.0).next_down().downgrade(),
+            -f64::from_bits(1)
+        );
+        assert_eq!(
+            ExtendedFloat::new(f64::MIN_POSITIVE)
+                .next_down()
+                .downgrade(),
+            f64::MIN_POSITIVE - f64::from_bits(1)
+        );
+        assert_eq!(
+            ExtendedFloat::new(2.0).next_down().downgrade(),
+            2.0 - f64::EPSILON
+        );
+        assert_eq!(
+            ExtendedFloat::new(1.0_f32).next_up().downgrade(),
+            1.0 + f32::EPSILON
+        );
+        assert_eq!(
+            ExtendedFloat::new(0.0_f32).next_down().downgrade(),
+            -f32::from_bits(1)
+        );
+        assert_eq!(
+            ExtendedFloat::new(-f32::MAX).next_up().downgrade(),
+            -f32::MAX.next_down()
+        );
+    }
+
+    #[test]
+    fn test_ulp() {
+        assert_eq!(ExtendedFloat::new(1.5).ulp().downgrade(), f64::EPSILON);
+        assert_eq!(
+            ExtendedFloat::new(2.0).ulp().downgrade(),
+            2.0 * f64::EPSILON
+        );
+        assert_eq!(
+            ExtendedFloat::new(-0.1).ulp().downgrade(),
+            f64::EPSILON / 16.0
+        );
+        assert_eq!(
+            ExtendedFloat::new(f64::MAX).ulp().downgrade(),
+            2f64.powi(971)
+        );
+        assert_eq!(
+            ExtendedFloat::new(1e-310).ulp().downgrade(),
+            f64::from_bits(1)
+        );
+        assert_eq!(ExtendedFloat::new(1.0_f32).ulp().downgrade(), f32::EPSILON);
+        assert_eq!(
+            ExtendedFloat::new(f32::MAX).ulp().downgrade(),
+            2f32.powi(104)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support infinite values")]
+    fn test_next_up_of_max() {
+        let _ = ExtendedFloat::new(f64::MAX).next_up();
+    }
+
+    #[test]
+    #[should_panic(expected = "ExtendedFloat doesn't support infinite values")]
+    fn test_next_down_of_min() {
+        let _ = ExtendedFloat::new(f32::MIN).next_down();
+    }
+}
@@ -0,0 +1,474 @@
+use super::{FormatSpec, ParseError, ParseErrorKind, TolerantFloat};
+use crate::traits::{DisplayableFloat, Tolerance};
+
+/// Sizes of digit groups in the integer part, counted from the decimal separator.
+///
+/// The group next to the separator has the primary size, all further groups have
+/// the secondary size: `1,234,567` uses 3 and 3, Indian `12,34,567` uses 3 and 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Grouping {
+    primary: u8,
+    secondary: u8,
+}
+
+impl Grouping {
+    /// Indian numbering system, a group of three and then groups of two: `12,34,567`
+    pub const INDIAN: Self = Self::new(3, 2);
+    /// No grouping at all
+    pub const NONE: Self = Self::new(0, 0);
+    /// Groups of three digits: `1,234,567`
+    pub const THOUSANDS: Self = Self::new(3, 3);
+
+    /// Creates a grouping pattern. A `primary` size of 0 disables grouping,
+    /// a `secondary` size of 0 repeats the primary one.
+    pub const fn new(primary: u8, secondary: u8) -> Self {
+        let secondary = if secondary == 0 { primary } else { secondary };
+        Self { primary, secondary }
+    }
+
+    /// Returns true if digits are grouped
+    #[inline]
+    fn is_enabled(&self) -> bool {
+        self.primary > 0
+    }
+
+    /// Returns true if a separator goes after the digit `index` of an integer part
+    /// with `len` digits, counting from the left
+    fn separator_after(&self, index: usize, len: usize) -> bool {
+        let remaining = len - index - 1;
+        let (primary, secondary) = (self.primary as usize, self.secondary as usize);
+        remaining >= primary && (remaining - primary).is_multiple_of(secondary)
+    }
+}
+
+/// Separators used to write and read numbers in a locale.
+///
+/// # Examples
+///
+/// ```
+/// use extended_float::types::ExtendedFloat;
+/// use extended_float::types::extended_float::{FormatSpec, NumberLocale, TrailingZeros};
+///
+/// let value = ExtendedFloat::new(1234567.89);
+/// assert_eq!(value.format_locale(&NumberLocale::EN), "1,234,567.89");
+/// assert_eq!(value.format_locale(&NumberLocale::DE), "1.234.567,89");
+/// assert_eq!(value.format_locale(&NumberLocale::IN), "12,34,567.89");
+///
+/// let spec = FormatSpec::new().fraction_digits(2).trailing_zeros(TrailingZeros::Keep);
+/// let value = ExtendedFloat::new(1500.0);
+/// assert_eq!(value.format_locale_with(&NumberLocale::DE, &spec), "1.500,00");
+///
+/// let parsed = ExtendedFloat::<f64>::parse_locale("1.500,00", &NumberLocale::DE).unwrap();
+/// assert_eq!(parsed, value);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberLocale {
+    decimal_separator: char,
+    group_separator: char,
+    grouping: Grouping,
+}
+
+impl NumberLocale {
+    /// Swiss: `1'234'567.89`
+    pub const CH: Self = Self::new('.', '\'', Grouping::THOUSANDS);
+    /// German, also used across continental Europe: `1.234.567,89`
+    pub const DE: Self = Self::new(',', '.', Grouping::THOUSANDS);
+    /// English: `1,234,567.89`
+    pub const EN: Self = Self::new('.', ',', Grouping::THOUSANDS);
+    /// French, grouped with narrow no-break spaces: `1 234 567,89`
+    pub const FR: Self = Self::new(',', '\u{202F}', Grouping::THOUSANDS);
+    /// Indian: `12,34,567.89`
+    pub const IN: Self = Self::new('.', ',', Grouping::INDIAN);
+
+    /// Creates a locale. The separators must differ and must not be digits or signs.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the separators are equal or one of them is an ASCII
+    /// digit, `+` or `-`, as numbers couldn't be read back.
+    pub const fn new(decimal_separator: char, group_separator: char, grouping: Grouping) -> Self {
+        assert!(
+            decimal_separator != group_separator,
+            "Decimal and group separators must differ"
+        );
+        assert!(
+            !is_reserved(decimal_separator) && !is_reserved(group_separator),
+            "Separators can't be digits or signs"
+        );
+
+        Self {
+            decimal_separator,
+            group_separator,
+            grouping,
+        }
+    }
+
+    /// Returns the decimal separator
+    #[inline]
+    pub fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    /// Returns the group separator
+    #[inline]
+    pub fn group_separator(&self) -> char {
+        self.group_separator
+    }
+
+    /// Returns the grouping pattern
+    #[inline]
+    pub fn grouping(&self) -> Grouping {
+        self.grouping
+    }
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        Self::EN
+    }
+}
+
+const fn is_reserved(separator: char) -> bool {
+    separator.is_ascii_digit() || separator == '+' || separator == '-'
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
+    /// Formats the value like [`format`](Self::format) with the separators of `locale`
+    pub fn format_locale(&self, locale: &NumberLocale) -> String {
+        self.format_locale_with(locale, &FormatSpec::new())
+    }
+
+    /// Formats the value like [`format_with`](Self::format_with) with the separators
+    /// of `locale`
+    pub fn format_locale_with(&self, locale: &NumberLocale, spec: &FormatSpec) -> String {
+        let plain = self.format_with(spec);
+
+        // Non-finite values have no digits to group
+        if !self.downgrade().is_finite() {
+            return plain;
+        }
+
+        let (sign, unsigned) = match plain.find(|c: char| c.is_ascii_digit()) {
+            Some(index) => plain.split_at(index),
+            None => ("", plain.as_str()),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let mut formatted = String::with_capacity(plain.len() + integer.len() / 2 + 4);
+        formatted.push_str(sign);
+        for (index, digit) in integer.chars().enumerate() {
+            formatted.push(digit);
+            if locale.grouping.is_enabled() && locale.grouping.separator_after(index, integer.len())
+            {
+                formatted.push(locale.group_separator);
+            }
+        }
+        if let Some(fraction) = fraction {
+            formatted.push(locale.decimal_separator);
+            formatted.push_str(fraction);
+        }
+
+        formatted
+    }
+
+    /// Parses a number written with the separators of `locale`.
+    ///
+    /// Accepts an optional sign, an integer part either without group separators or
+    /// grouped exactly as [`format_locale`](Self::format_locale) writes it, and an
+    /// optional fractional part. The whole input must be consumed, positions in errors
+    /// are byte offsets into `s`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    /// use extended_float::types::extended_float::{NumberLocale, ParseErrorKind};
+    ///
+    /// let parsed = ExtendedFloat::<f64>::parse_locale("-12,34,567.5", &NumberLocale::IN);
+    /// assert_eq!(parsed.unwrap(), ExtendedFloat::new(-1234567.5));
+    ///
+    /// // A misplaced separator is rejected rather than ignored
+    /// let error = ExtendedFloat::<f64>::parse_locale("1,5", &NumberLocale::EN).unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidDigit);
+    /// assert_eq!(error.position(), 1);
+    /// ```
+    pub fn parse_locale(s: &str, locale: &NumberLocale) -> Result<Self, ParseError> {
+        let mut normalized = String::with_capacity(s.len());
+        let mut chars = s.char_indices().peekable();
+
+        if let Some(&(_, sign @ ('+' | '-'))) = chars.peek() {
+            normalized.push(sign);
+            chars.next();
+        }
+        let significand_start = normalized.len();
+
+        // Integer part, remembering where separators were to validate the groups
+        let grouping = locale.grouping;
+        let mut group_len = 0;
+        let mut groups = 0;
+        let mut last_separator = 0;
+        while let Some(&(position, c)) = chars.peek() {
+            if c.is_ascii_digit() {
+                normalized.push(c);
+                group_len += 1;
+            } else if c == locale.group_separator && grouping.is_enabled() {
+                let expected = grouping.secondary as usize;
+                let valid = match groups {
+                    0 => group_len > 0 && group_len <= expected,
+                    _ => group_len == expected,
+                };
+                if !valid {
+                    return Err(ParseError::new(ParseErrorKind::InvalidDigit, position));
+                }
+                groups += 1;
+                group_len = 0;
+                last_separator = position;
+            } else {
+                break;
+            }
+            chars.next();
+        }
+
+        // The last group is next to the decimal separator and has the primary size
+        if groups > 0 && group_len != grouping.primary as usize {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidDigit,
+                last_separator,
+            ));
+        }
+
+        if let Some(&(_, c)) = chars.peek()
+            && c == locale.decimal_separator
+        {
+            normalized.push('.');
+            chars.next();
+            while let Some(&(_, c)) = chars.peek()
+                && c.is_ascii_digit()
+            {
+                normalized.push(c);
+                chars.next();
+            }
+        }
+
+        if let Some(&(position, _)) = chars.peek() {
+            return Err(ParseError::new(ParseErrorKind::InvalidDigit, position));
+        }
+
+        if normalized.len() == significand_start || normalized[significand_start..] == *"." {
+            return Err(ParseError::new(ParseErrorKind::Empty, significand_start));
+        }
+
+        // Only ASCII digits, a sign and a point are left, all syntax errors are caught
+        // above and the remaining failures don't carry a meaningful position
+        Self::parse_bytes(normalized.as_bytes())
+            .map(|(value, _)| value)
+            .map_err(|error| ParseError::new(error.kind(), 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::types::ExtendedFloat;
+    use crate::types::extended_float::TrailingZeros;
+
+    #[test]
+    fn test_format_locale() {
+        let value = ExtendedFloat::new(1234567.89);
+        assert_eq!(value.format_locale(&NumberLocale::EN), "1,234,567.89");
+        assert_eq!(value.format_locale(&NumberLocale::DE), "1.234.567,89");
+        assert_eq!(
+            value.format_locale(&NumberLocale::FR),
+            "1\u{202F}234\u{202F}567,89"
+        );
+        assert_eq!(value.format_locale(&NumberLocale::CH), "1'234'567.89");
+        assert_eq!(value.format_locale(&NumberLocale::IN), "12,34,567.89");
+
+        assert_eq!(
+            ExtendedFloat::new(-123456789.0).format_locale(&NumberLocale::IN),
+            "-12,34,56,789"
+        );
+        assert_eq!(
+            ExtendedFloat::new(-123.5).format_locale(&NumberLocale::DE),
+            "-123,5"
+        );
+        assert_eq!(
+            ExtendedFloat::new(1000.0).format_locale(&NumberLocale::EN),
+            "1,000"
+        );
+        assert_eq!(
+            ExtendedFloat::new(0.25).format_locale(&NumberLocale::DE),
+            "0,25"
+        );
+        assert_eq!(
+            ExtendedFloat::new(0.0).format_locale(&NumberLocale::IN),
+            "0"
+        );
+    }
+
+    #[test]
+    fn test_format_locale_custom() {
+        let no_grouping = NumberLocale::new(',', ' ', Grouping::NONE);
+        assert_eq!(
+            ExtendedFloat::new(1234567.5).format_locale(&no_grouping),
+            "1234567,5"
+        );
+
+        let myriads = NumberLocale::new('.', ',', Grouping::new(4, 0));
+        assert_eq!(
+            ExtendedFloat::new(123456789.0).format_locale(&myriads),
+            "1,2345,6789"
+        );
+    }
+
+    #[test]
+    fn test_format_locale_with_spec() {
+        let spec = FormatSpec::new()
+            .fraction_digits(2)
+            .trailing_zeros(TrailingZeros::Keep);
+        let value = ExtendedFloat::new(1234.5);
+        assert_eq!(
+            value.format_locale_with(&NumberLocale::DE, &spec),
+            "1.234,50"
+        );
+        assert_eq!(
+            value.format_locale_with(&NumberLocale::IN, &spec),
+            "1,234.50"
+        );
+
+        let spec = spec.sign(crate::types::extended_float::SignPolicy::Always);
+        assert_eq!(
+            value.format_locale_with(&NumberLocale::EN, &spec),
+            "+1,234.50"
+        );
+    }
+
+    #[test]
+    fn test_parse_locale() {
+        let parse = |s: &str, locale: &NumberLocale| {
+            ExtendedFloat::<f64>::parse_locale(s, locale).map(|value| value.downgrade())
+        };
+
+        assert_eq!(parse("1,234,567.89", &NumberLocale::EN), Ok(1234567.89));
+        assert_eq!(parse("1.234.567,89", &NumberLocale::DE), Ok(1234567.89));
+        assert_eq!(parse("12,34,567.89", &NumberLocale::IN), Ok(1234567.89));
+        assert_eq!(parse("1'234.5", &NumberLocale::CH), Ok(1234.5));
+        assert_eq!(parse("1\u{202F}234,5", &NumberLocale::FR), Ok(1234.5));
+        assert_eq!(parse("-0,5", &NumberLocale::DE), Ok(-0.5));
+        assert_eq!(parse("+999", &NumberLocale::EN), Ok(999.0));
+        assert_eq!(parse(",5", &NumberLocale::DE), Ok(0.5));
+        assert_eq!(parse("12.", &NumberLocale::EN), Ok(12.0));
+
+        // Ungrouped integers are accepted as well
+        assert_eq!(parse("1234567,89", &NumberLocale::DE), Ok(1234567.89));
+    }
+
+    #[test]
+    fn test_parse_locale_errors() {
+        let error = |s: &str, locale: &NumberLocale| {
+            let error = ExtendedFloat::<f64>::parse_locale(s, locale).unwrap_err();
+            (error.kind(), error.position())
+        };
+
+        assert_eq!(error("", &NumberLocale::EN), (ParseErrorKind::Empty, 0));
+        assert_eq!(error("-", &NumberLocale::EN), (ParseErrorKind::Empty, 1));
+        assert_eq!(error(",", &NumberLocale::DE), (ParseErrorKind::Empty, 0));
+
+        // Misplaced group separators
+        assert_eq!(
+            error("1,5", &NumberLocale::EN),
+            (ParseErrorKind::InvalidDigit, 1)
+        );
+        assert_eq!(
+            error("1,23,456", &NumberLocale::EN),
+            (ParseErrorKind::InvalidDigit, 4)
+        );
+        assert_eq!(
+            error("1234,567", &NumberLocale::EN),
+            (ParseErrorKind::InvalidDigit, 4)
+        );
+        assert_eq!(
+            error(",123", &NumberLocale::EN),
+            (ParseErrorKind::InvalidDigit, 0)
+        );
+        assert_eq!(
+            error("1,234,", &NumberLocale::EN),
+            (ParseErrorKind::InvalidDigit, 5)
+        );
+        assert_eq!(
+            error("1,234,567", &NumberLocale::IN),
+            (ParseErrorKind::InvalidDigit, 5)
+        );
+
+        // Separators of another locale
+        assert_eq!(
+            error("1.234,5", &NumberLocale::EN),
+            (ParseErrorKind::InvalidDigit, 5)
+        );
+        assert_eq!(
+            error("1,5", &NumberLocale::CH),
+            (ParseErrorKind::InvalidDigit, 1)
+        );
+
+        // No grouping in the fraction, no exponent
+        assert_eq!(
+            error("0.123,4", &NumberLocale::EN),
+            (ParseErrorKind::InvalidDigit, 5)
+        );
+        assert_eq!(
+            error("1e5", &NumberLocale::EN),
+            (ParseErrorKind::InvalidDigit, 1)
+        );
+
+        // Positions are byte offsets
+        assert_eq!(
+            error("1\u{202F}2\u{202F}345", &NumberLocale::FR),
+            (ParseErrorKind::InvalidDigit, 5)
+        );
+    }
+
+    #[test]
+    fn test_format_parse_round_trip() {
+        let locales = [
+            NumberLocale::EN,
+            NumberLocale::DE,
+            NumberLocale::FR,
+            NumberLocale::CH,
+            NumberLocale::IN,
+        ];
+        let values = [
+            0.0,
+            1.5,
+            -12.25,
+            999.0,
+            1000.0,
+            123456.789,
+            -98765432.1,
+            1e20,
+        ];
+
+        for locale in &locales {
+            for value in values {
+                let value = ExtendedFloat::new(value);
+                let formatted = value.format_locale(locale);
+                assert_eq!(
+                    ExtendedFloat::parse_locale(&formatted, locale),
+                    Ok(value),
+                    "Failed for {}",
+                    formatted
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Decimal and group separators must differ")]
+    fn test_locale_same_separators() {
+        let _ = NumberLocale::new('.', '.', Grouping::THOUSANDS);
+    }
+}
@@ -1,11 +1,46 @@
 mod structs;
-pub use structs::ExtendedFloat;
+pub use structs::{ExtendedFloat, TolerantFloat};
+
+mod tolerance;
+pub use tolerance::{Absolute, Combined, DefaultTolerance, Relative, Ulps};
+
+mod formatted;
+pub use formatted::{FORMAT_BUFFER_LEN, FormattedFloat};
+
+mod format_spec;
+pub use format_spec::{FormatSpec, SignPolicy, TrailingZeros};
+
+mod locale;
+pub use locale::{Grouping, NumberLocale};
+
+mod rounding;
+pub use rounding::RoundingMode;
+
+mod accumulator;
+pub use accumulator::Accumulator;
+
+mod wrapper;
+
+mod hashable;
+pub use hashable::HashableExtendedFloat;
+
+mod total_ord;
+pub use total_ord::TotalOrd;
 
 mod impl_conversions;
-pub use impl_conversions::ConversionError;
+pub use impl_conversions::{ConversionError, ParseError, ParseErrorKind};
+
+mod decimal;
+mod impl_step;
+pub use impl_step::StepError;
 
 mod impl_checked_ops;
 mod impl_display;
 mod impl_eq;
+mod impl_iter;
+mod impl_math;
 mod impl_ops;
 mod impl_ord;
+mod impl_parse;
+mod impl_round;
+mod impl_ulps;
@@ -0,0 +1,51 @@
+/// Rule for rounding a value to fewer decimal digits.
+///
+/// Rounding is applied to the decimal value as it's displayed, after floating-point
+/// artifacts are removed, so `2.675` is a tie even though it's stored slightly below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Round to the nearest value, ties away from zero
+    HalfUp,
+    /// Round to the nearest value, ties toward zero
+    HalfDown,
+    /// Round to the nearest value, ties to the even digit (banker's rounding).
+    /// This is how primitive floats are formatted.
+    #[default]
+    HalfEven,
+    /// Round away from zero
+    Up,
+    /// Round toward zero, truncating the dropped digits
+    Down,
+    /// Round toward positive infinity
+    Ceiling,
+    /// Round toward negative infinity
+    Floor,
+}
+
+impl RoundingMode {
+    /// Decides whether the magnitude of a value should be rounded up (away from zero).
+    ///
+    /// `half` compares the dropped digits with half a unit of the last kept place:
+    /// less, equal or greater. Dropped digits are never all zero.
+    #[inline]
+    pub(crate) fn rounds_up(
+        self,
+        negative: bool,
+        half: std::cmp::Ordering,
+        last_kept_odd: bool,
+    ) -> bool {
+        use std::cmp::Ordering;
+
+        match self {
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfDown => half == Ordering::Greater,
+            RoundingMode::HalfEven => {
+                half == Ordering::Greater || (half == Ordering::Equal && last_kept_odd)
+            }
+            RoundingMode::Up => true,
+            RoundingMode::Down => false,
+            RoundingMode::Ceiling => !negative,
+            RoundingMode::Floor => negative,
+        }
+    }
+}
@@ -1,16 +1,52 @@
 use core::hint::likely;
-use std::fmt::Write;
+use std::fmt;
+use std::marker::PhantomData;
 
-use crate::traits::{DisplayableFloat, Float};
+use super::DefaultTolerance;
+use crate::traits::{DisplayableFloat, Tolerance};
 use crate::utils::{check_invalid_float, is_valid_float};
 
-#[derive(Debug, Clone, Copy)]
-pub struct ExtendedFloat<T: DisplayableFloat>(T);
+/// Float wrapper whose equality and ordering follow the `Tol` policy.
+///
+/// The policy is chosen at compile time, see [`Tolerance`] for writing one and the
+/// built-in [`Absolute`](super::Absolute), [`Relative`](super::Relative),
+/// [`Combined`](super::Combined) and [`Ulps`](super::Ulps). Everything except the
+/// comparisons behaves exactly like [`ExtendedFloat`], which is this type with the
+/// [`DefaultTolerance`].
+///
+/// # Examples
+///
+/// ```
+/// use extended_float::types::ExtendedFloat;
+/// use extended_float::types::extended_float::{Absolute, TolerantFloat};
+///
+/// // Crypto quantities need to tell apart values far below the default epsilon
+/// type Quantity = TolerantFloat<f64, Absolute<18>>;
+///
+/// assert_ne!(Quantity::new(1e-14), Quantity::new(2e-14));
+/// assert_eq!(ExtendedFloat::new(1e-14), ExtendedFloat::new(2e-14));
+/// ```
+pub struct TolerantFloat<T: DisplayableFloat, Tol: Tolerance<T>>(T, PhantomData<fn() -> Tol>);
+
+/// Float wrapper with the default comparison tolerance, see [`DefaultTolerance`].
+pub type ExtendedFloat<T> = TolerantFloat<T, DefaultTolerance>;
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Clone for TolerantFloat<T, Tol> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Copy for TolerantFloat<T, Tol> {}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> fmt::Debug for TolerantFloat<T, Tol> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(Tol::NAME).field(&self.0).finish()
+    }
+}
 
 // TODO: From trait
-// TODO: fast from string creation
-// TODO: round_by_step?
-// TODO: ceil_by_step?
 // TODO: Memory layout optimization:
 //  No explicit attention to cache-line alignment
 //  Consider padding or alignment attributes for better cache performance
@@ -19,7 +55,7 @@ pub struct ExtendedFloat<T: DisplayableFloat>(T);
 //  Consider implementing batch operations with SIMD
 // TODO: all the operation comparison with f64, popular crates?
 // TODO: another branch predictions (likely)?
-impl<T: DisplayableFloat> ExtendedFloat<T> {
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TolerantFloat<T, Tol> {
     /// Creates a new ExtendedFloat instance with validation.
     ///
     /// # Panics
@@ -30,7 +66,7 @@ impl<T: DisplayableFloat> ExtendedFloat<T> {
     pub fn new(value: T) -> Self {
         // branch prediction optimisation
         if likely(is_valid_float(value)) {
-            return Self(value);
+            return Self(value, PhantomData);
         }
 
         // Invalid value detected, get details for more specific error message
@@ -73,13 +109,31 @@ impl<T: DisplayableFloat> ExtendedFloat<T> {
     /// The caller must ensure the value is neither NaN nor infinite.
     #[inline]
     pub unsafe fn new_unchecked(value: T) -> Self {
-        Self(value)
+        Self(value, PhantomData)
+    }
+
+    /// Converts to the same value compared with another tolerance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use extended_float::types::ExtendedFloat;
+    /// use extended_float::types::extended_float::Ulps;
+    ///
+    /// let sum = ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2);
+    /// assert_eq!(sum, ExtendedFloat::new(0.3));
+    /// assert_ne!(sum.with_tolerance::<Ulps<0>>(), ExtendedFloat::new(0.3).with_tolerance());
+    /// ```
+    #[inline(always)]
+    pub fn with_tolerance<U: Tolerance<T>>(self) -> TolerantFloat<T, U> {
+        TolerantFloat(self.0, PhantomData)
     }
 
     /// Formats a floating-point number as a string with precision that accounts for floating-point errors.
     ///
     /// This method intelligently formats floating-point numbers by:
-    /// 1. Using dynamically calculated precision based on the number's binary exponent
+    /// 1. Using dynamically calculated precision based on the number's binary exponent,
+    ///    rounding the exact binary value half to even at it
     /// 2. Treating values smaller than epsilon as zero
     /// 3. Preserving very large values (> decimal_precision) without modification
     /// 4. Removing trailing zeros and decimal point when unnecessary
@@ -87,33 +141,12 @@ impl<T: DisplayableFloat> ExtendedFloat<T> {
     /// Note: Values larger than 1/epsilon are formatted as-is since errors occur in the
     /// integer part of the number, and rounding the integer part shouldn't be done in
     /// trading/financial contexts.
+    ///
+    /// See [`formatted`](Self::formatted), [`format_to`](Self::format_to) and
+    /// [`format_into`](Self::format_into) for allocation-free alternatives, and
+    /// [`round_dp`](Self::round_dp) to round the shown value with an explicit mode.
     pub fn format(&self) -> String {
-        let value = self.0;
-        let precision = self.0.precision();
-
-        // Handle special case of zero
-        if value.abs() <= <T as Float>::epsilon() {
-            return "0".to_string();
-        }
-
-        // if value is too big, just return it as is
-        if value.abs() > T::decimal_precision() {
-            return value.to_string();
-        }
-
-        let mut formatted = String::with_capacity(32);
-        write!(formatted, "{:.*}", precision, value).unwrap();
-
-        // Remove trailing zeros and decimal point if needed
-        if let Some(dot_index) = formatted.find('.') {
-            let trimmed_len = formatted[dot_index..]
-                .trim_end_matches('0')
-                .trim_end_matches('.')
-                .len();
-            formatted.truncate(dot_index + trimmed_len);
-        }
-
-        formatted
+        String::from(self.formatted().as_str())
     }
 
     #[inline(always)]
@@ -182,6 +215,9 @@ impl<T: DisplayableFloat> ExtendedFloat<T> {
 #[allow(clippy::excessive_precision)]
 mod tests {
     use super::*;
+    use crate::constants::f32::{
+        DECIMAL_PRECISION as DECIMAL_PRECISION_F32, EPSILON as EPSILON_F32,
+    };
     use crate::constants::f64::{
         DECIMAL_PRECISION as DECIMAL_PRECISION_F64, EPSILON as EPSILON_F64,
     };
@@ -211,6 +247,17 @@ mod tests {
         ExtendedFloat::new(f64::INFINITY);
     }
 
+    #[test]
+    fn test_try_new_validation_f32() {
+        assert!(ExtendedFloat::try_new(1.0_f32).is_some());
+        assert!(ExtendedFloat::try_new(-1.0_f32).is_some());
+        assert!(ExtendedFloat::try_new(0.0_f32).is_some());
+
+        assert!(ExtendedFloat::try_new(f32::NAN).is_none());
+        assert!(ExtendedFloat::try_new(f32::INFINITY).is_none());
+        assert!(ExtendedFloat::try_new(f32::NEG_INFINITY).is_none());
+    }
+
     mod format_tests {
         use pretty_assertions::assert_eq;
 
@@ -448,6 +495,43 @@ mod tests {
             }
         }
 
+        #[test]
+        fn test_f32_formatting() {
+            assert_eq!(
+                (ExtendedFloat::new(0.1_f32) + ExtendedFloat::new(0.2_f32)).to_string(),
+                "0.3"
+            );
+            assert_eq!(ExtendedFloat::new(1.15_f32).to_string(), "1.15");
+            assert_eq!(ExtendedFloat::new(123.456_f32).to_string(), "123.456");
+            assert_eq!(ExtendedFloat::new(-0.001_f32).to_string(), "-0.001");
+            assert_eq!(ExtendedFloat::new(1234.5678_f32).to_string(), "1234.57");
+            assert_eq!(
+                ExtendedFloat::new(std::f32::consts::PI).to_string(),
+                "3.14159"
+            );
+
+            // Values up to epsilon collapse to zero
+            assert_eq!(ExtendedFloat::new(EPSILON_F32).to_string(), "0");
+            assert_eq!(ExtendedFloat::new(-EPSILON_F32 / 2.0).to_string(), "0");
+
+            // Values above the decimal precision threshold are formatted as-is
+            let big = DECIMAL_PRECISION_F32 * 3.5;
+            assert_eq!(ExtendedFloat::new(big).to_string(), big.to_string());
+            assert_eq!(
+                ExtendedFloat::new(f32::MAX).to_string(),
+                f32::MAX.to_string()
+            );
+        }
+
+        #[test]
+        fn test_dynamic_precision_f32() {
+            // Every extra binary order of magnitude eats into the 6 available digits
+            assert_eq!(ExtendedFloat::new(1.000001_f32).to_string(), "1.000001");
+            assert_eq!(ExtendedFloat::new(10.00001_f32).to_string(), "10.00001");
+            assert_eq!(ExtendedFloat::new(100.0001_f32).to_string(), "100.0001");
+            assert_eq!(ExtendedFloat::new(100.00001_f32).to_string(), "100");
+        }
+
         #[test]
         fn test_parse_format_equivalence() {
             // Test that parsing the formatted string back to f64 gives equivalent results
@@ -0,0 +1,251 @@
+use std::marker::PhantomData;
+
+use crate::traits::{DisplayableFloat, Float, Tolerance};
+
+/// Tolerance of [`ExtendedFloat`](super::ExtendedFloat): the values are equal if they
+/// differ by at most `Float::epsilon()`, or by less than epsilon relative to the
+/// smaller magnitude.
+///
+/// Epsilon is 1e-12 for `f64` and 1e-6 for `f32`, which suits prices and amounts in
+/// the usual ranges but treats all tiny values as equal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DefaultTolerance;
+
+impl<T: DisplayableFloat> Tolerance<T> for DefaultTolerance {
+    const NAME: &'static str = "ExtendedFloat";
+
+    fn approx_eq(a: T, b: T) -> bool {
+        let abs_diff = (a - b).abs();
+        let epsilon = <T as Float>::epsilon();
+
+        if abs_diff <= epsilon {
+            return true;
+        }
+
+        abs_diff < epsilon * a.abs().min(b.abs())
+    }
+}
+
+/// The values are equal if they differ by at most `10^-DIGITS`.
+///
+/// Suits quantities with a known smallest unit, e.g. `Absolute<8>` for satoshis. For
+/// large magnitudes the bound falls below the float resolution and only identical
+/// values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use extended_float::types::extended_float::{Absolute, TolerantFloat};
+///
+/// type Quantity = TolerantFloat<f64, Absolute<16>>;
+///
+/// assert_ne!(Quantity::new(1e-14), Quantity::new(2e-14));
+/// assert_eq!(Quantity::new(1e-14) + Quantity::new(2e-14), Quantity::new(3e-14));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Absolute<const DIGITS: u32>;
+
+impl<T: DisplayableFloat, const DIGITS: u32> Tolerance<T> for Absolute<DIGITS> {
+    fn approx_eq(a: T, b: T) -> bool {
+        (a - b).abs() <= power_of_ten::<T>(DIGITS)
+    }
+}
+
+/// The values are equal if they differ by at most `10^-DIGITS` of the larger
+/// magnitude.
+///
+/// Suits notionals and other values compared by significant digits. Nothing is close
+/// to zero except zero itself, use [`Combined`] with [`Absolute`] to also accept
+/// residues of cancellation.
+///
+/// # Examples
+///
+/// ```
+/// use extended_float::types::extended_float::{Relative, TolerantFloat};
+///
+/// type Notional = TolerantFloat<f64, Relative<9>>;
+///
+/// assert_eq!(Notional::new(1e12), Notional::new(1e12 + 0.5));
+/// assert_ne!(Notional::new(1e12), Notional::new(1e12 + 5000.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Relative<const DIGITS: u32>;
+
+impl<T: DisplayableFloat, const DIGITS: u32> Tolerance<T> for Relative<DIGITS> {
+    fn approx_eq(a: T, b: T) -> bool {
+        (a - b).abs() <= power_of_ten::<T>(DIGITS) * a.abs().max(b.abs())
+    }
+}
+
+/// The values are equal if either of the tolerances accepts them.
+///
+/// # Examples
+///
+/// ```
+/// use extended_float::types::extended_float::{Absolute, Combined, Relative, TolerantFloat};
+///
+/// type Amount = TolerantFloat<f64, Combined<Absolute<12>, Relative<14>>>;
+///
+/// let residue = Amount::new(0.3) - (Amount::new(0.1) + Amount::new(0.2));
+/// assert_eq!(residue, Amount::new(0.0));
+/// assert_eq!(Amount::new(1e15), Amount::new(1e15 + 1.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Combined<A, B>(PhantomData<(A, B)>);
+
+impl<T: DisplayableFloat, A: Tolerance<T>, B: Tolerance<T>> Tolerance<T> for Combined<A, B> {
+    fn approx_eq(a: T, b: T) -> bool {
+        A::approx_eq(a, b) || B::approx_eq(a, b)
+    }
+}
+
+/// The values are equal if at most `N` floats lie between them, counting the larger
+/// one.
+///
+/// The check doesn't depend on the magnitude, which suits comparing results of the
+/// same computation made on different platforms. Both zeros are equal, and the
+/// smallest values of opposite signs are a few ULPs apart.
+///
+/// # Examples
+///
+/// ```
+/// use extended_float::types::extended_float::{TolerantFloat, Ulps};
+///
+/// type Result = TolerantFloat<f64, Ulps<4>>;
+///
+/// assert_eq!(Result::new(0.1) + Result::new(0.2), Result::new(0.3));
+/// assert_ne!(Result::new(1e-300), Result::new(2e-300));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Ulps<const N: u32>;
+
+impl<T: DisplayableFloat, const N: u32> Tolerance<T> for Ulps<N> {
+    fn approx_eq(a: T, b: T) -> bool {
+        a.ulp_index().abs_diff(b.ulp_index()) <= u64::from(N)
+    }
+}
+
+/// Returns `10^-digits`
+#[inline]
+fn power_of_ten<T: DisplayableFloat>(digits: u32) -> T {
+    let exponent = i32::try_from(digits).unwrap_or(i32::MAX);
+    T::from(10).unwrap().powi(-exponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::types::ExtendedFloat;
+    use crate::types::extended_float::TolerantFloat;
+
+    #[test]
+    fn test_default_tolerance_is_unchanged() {
+        type Explicit = TolerantFloat<f64, DefaultTolerance>;
+
+        assert_eq!(Explicit::new(0.1) + Explicit::new(0.2), Explicit::new(0.3));
+        assert_eq!(Explicit::new(1e-14), Explicit::new(2e-14));
+        assert_ne!(Explicit::new(1e12), Explicit::new(1e12 + 5.0));
+        assert_eq!(ExtendedFloat::new(1.0).with_tolerance(), Explicit::new(1.0));
+    }
+
+    #[test]
+    fn test_absolute_tolerance() {
+        type Quantity = TolerantFloat<f64, Absolute<14>>;
+
+        assert_ne!(Quantity::new(1e-14), Quantity::new(3e-14));
+        assert_eq!(Quantity::new(1e-14), Quantity::new(1.5e-14));
+        assert_eq!(
+            Quantity::new(1e-14).cmp(&Quantity::new(3e-14)),
+            Ordering::Less
+        );
+        assert_eq!(Quantity::new(0.1) + Quantity::new(0.2), Quantity::new(0.3));
+        assert_eq!(Quantity::new(2e-14), 2.000_001e-14);
+
+        type Coarse = TolerantFloat<f32, Absolute<2>>;
+        assert_eq!(Coarse::new(1.004), Coarse::new(1.0));
+        assert_ne!(Coarse::new(1.02), Coarse::new(1.0));
+    }
+
+    #[test]
+    fn test_relative_tolerance() {
+        type Notional = TolerantFloat<f64, Relative<9>>;
+
+        assert_eq!(Notional::new(1e12), Notional::new(1e12 + 500.0));
+        assert_ne!(Notional::new(1e12), Notional::new(1e12 + 5_000.0));
+        assert_eq!(Notional::new(1e-20), Notional::new(1.000_000_000_1e-20));
+        assert_ne!(Notional::new(1e-20), Notional::new(0.0));
+        assert_eq!(
+            Notional::new(1e12).partial_cmp(&Notional::new(1e12 - 100.0)),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn test_combined_tolerance() {
+        type Amount = TolerantFloat<f64, Combined<Absolute<12>, Relative<14>>>;
+
+        let residue = Amount::new(0.3) - (Amount::new(0.1) + Amount::new(0.2));
+        assert_eq!(residue, Amount::new(0.0));
+        assert_eq!(Amount::new(1e15), Amount::new(1e15 + 1.0));
+        assert_ne!(Amount::new(1e15), Amount::new(1e15 + 100.0));
+        assert_ne!(Amount::new(1.0), Amount::new(1.0 + 1e-11));
+    }
+
+    #[test]
+    fn test_ulps_tolerance() {
+        type Exact = TolerantFloat<f64, Ulps<0>>;
+        type Close = TolerantFloat<f64, Ulps<1>>;
+
+        assert_ne!(Exact::new(0.1) + Exact::new(0.2), Exact::new(0.3));
+        assert_eq!(Exact::new(0.0), Exact::new(-0.0));
+        assert_eq!(Close::new(0.1) + Close::new(0.2), Close::new(0.3));
+        assert_eq!(Close::new(1e300), Close::new(1e300 * (1.0 + f64::EPSILON)));
+        assert_ne!(Close::new(1.0), Close::new(1.0 + 2.0 * f64::EPSILON));
+        assert_ne!(Close::new(f64::MAX), Close::new(-f64::MAX));
+
+        let smallest = f64::from_bits(1);
+        assert_eq!(Close::new(smallest), Close::new(0.0));
+        assert_ne!(Close::new(smallest), Close::new(-smallest));
+
+        type CloseF32 = TolerantFloat<f32, Ulps<1>>;
+        assert_eq!(CloseF32::new(1.0), CloseF32::new(1.0 + f32::EPSILON));
+        assert_ne!(CloseF32::new(1.0), CloseF32::new(1.0 + 2.0 * f32::EPSILON));
+    }
+
+    #[test]
+    fn test_custom_tolerance_keeps_other_behavior() {
+        type Quantity = TolerantFloat<f64, Absolute<18>>;
+
+        let value = Quantity::new(0.1) + 0.2;
+        assert_eq!(value.format(), "0.3");
+        assert_eq!(format!("{:?}", value), "TolerantFloat(0.30000000000000004)");
+        assert_eq!(
+            format!("{:?}", value.with_tolerance::<DefaultTolerance>()),
+            "ExtendedFloat(0.30000000000000004)"
+        );
+
+        struct Cents;
+        impl Tolerance<f64> for Cents {
+            const NAME: &'static str = "Price";
+
+            fn approx_eq(a: f64, b: f64) -> bool {
+                (a * 100.0).round() == (b * 100.0).round()
+            }
+        }
+        assert_eq!(
+            format!("{:?}", TolerantFloat::<f64, Cents>::new(1.5)),
+            "Price(1.5)"
+        );
+        assert_eq!("1.5".parse::<Quantity>().unwrap(), Quantity::new(1.5));
+        assert_eq!(
+            [Quantity::new(1e-15), Quantity::new(2e-15)]
+                .iter()
+                .sum::<Quantity>(),
+            Quantity::new(3e-15)
+        );
+    }
+}
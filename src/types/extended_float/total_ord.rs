@@ -0,0 +1,218 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use super::wrapper::impl_wrapper;
+use super::{DefaultTolerance, TolerantFloat};
+use crate::traits::{DisplayableFloat, Tolerance};
+
+/// Wrapper ordering [`ExtendedFloat`](super::ExtendedFloat) by the exact value, for
+/// sorting and `BTreeMap` keys.
+///
+/// `ExtendedFloat`'s `Ord` treats values within the tolerance as equal, which isn't
+/// transitive: sorting can give different results for different input orders and
+/// `BTreeMap` lookups can miss. This wrapper compares with
+/// [`total_cmp`](TolerantFloat::total_cmp) instead, a total order where equal means
+/// identical apart from the sign of zero. `Eq` and `Hash` agree with it. A
+/// [`TolerantFloat`] with any other policy is ordered the same way, the policy is
+/// only kept for [`get`](Self::get).
+///
+/// To also merge values that differ only by rounding errors, round them first, e.g.
+/// with [`round_dp`](TolerantFloat::round_dp) or
+/// [`round_by_step`](TolerantFloat::round_by_step).
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use extended_float::types::ExtendedFloat;
+/// use extended_float::types::extended_float::TotalOrd;
+///
+/// let mut book = BTreeMap::new();
+/// for (price, size) in [(101.5, 3), (101.25, 1), (101.5, 2)] {
+///     *book.entry(TotalOrd::new(ExtendedFloat::new(price))).or_insert(0) += size;
+/// }
+///
+/// let levels: Vec<_> = book.iter().map(|(price, size)| (price.to_string(), *size)).collect();
+/// assert_eq!(levels, [("101.25".to_string(), 1), ("101.5".to_string(), 5)]);
+/// ```
+pub struct TotalOrd<T: DisplayableFloat, Tol: Tolerance<T> = DefaultTolerance>(
+    TolerantFloat<T, Tol>,
+);
+
+impl_wrapper!(TotalOrd, "total ordering");
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> PartialEq for TotalOrd<T, Tol> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Eq for TotalOrd<T, Tol> {}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Ord for TotalOrd<T, Tol> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> PartialOrd for TotalOrd<T, Tol> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Hash for TotalOrd<T, Tol> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.downgrade().ulp_index().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::test_utils::{generator, hash_of};
+    use crate::types::ExtendedFloat;
+    use crate::types::extended_float::Relative;
+
+    /// Values that stress the tolerant comparison: chains of neighbours closer than
+    /// epsilon, both zeros, values around epsilon and duplicates
+    fn adversarial_values(next: &mut impl FnMut() -> u64) -> Vec<TotalOrd<f64>> {
+        let mut values = Vec::new();
+        while values.len() < 300 {
+            let value = match next() % 6 {
+                0 => 0.0,
+                1 => -0.0,
+                2 => (next() % 5) as f64 * 0.4e-12 - 1e-12,
+                3 => 1.0 + (next() % 10) as f64 * 0.6e-12,
+                4 => 0.1 * (next() % 4) as f64,
+                _ => f64::from_bits(0.3f64.to_bits() + next() % 3) - 1e12 * (next() % 2) as f64,
+            };
+            values.push(TotalOrd::new(ExtendedFloat::new(value)));
+        }
+        values
+    }
+
+    fn shuffle<V>(values: &mut [V], next: &mut impl FnMut() -> u64) {
+        for index in (1..values.len()).rev() {
+            values.swap(index, (next() % (index as u64 + 1)) as usize);
+        }
+    }
+
+    fn bits(values: &[TotalOrd<f64>]) -> Vec<u64> {
+        // Both zeros are the same value here
+        values
+            .iter()
+            .map(|value| (value.get().downgrade() + 0.0).to_bits())
+            .collect()
+    }
+
+    #[test]
+    fn test_total_order_is_transitive() {
+        let a = ExtendedFloat::new(1.0);
+        let b = ExtendedFloat::new(1.0 + 0.6e-12);
+        let c = ExtendedFloat::new(1.0 + 1.2e-12);
+        // The tolerant order can't sort these consistently
+        assert!(a == b && b == c && a < c);
+
+        let (a, b, c) = (TotalOrd::new(a), TotalOrd::new(b), TotalOrd::new(c));
+        assert!(a < b && b < c && a < c);
+        assert_eq!(
+            TotalOrd::new(ExtendedFloat::new(-0.0)),
+            ExtendedFloat::new(0.0).into()
+        );
+        assert!(TotalOrd::new(ExtendedFloat::new(-1e-300)) < ExtendedFloat::new(-0.0).into());
+        assert!(TotalOrd::new(ExtendedFloat::new(-f64::MAX)) < ExtendedFloat::new(-1e300).into());
+        assert!(TotalOrd::new(ExtendedFloat::new(0.5_f32)) > ExtendedFloat::new(0.25_f32).into());
+        assert_eq!(format!("{} {:?}", a, a), "1 TotalOrd(1.0)");
+    }
+
+    #[test]
+    fn test_sort_and_dedup_are_stable_on_adversarial_inputs() {
+        let mut next = generator(0x2545_F491_4F6C_DD1D);
+
+        for _ in 0..100 {
+            let values = adversarial_values(&mut next);
+
+            let mut expected = values.clone();
+            expected.sort();
+            for pair in expected.windows(2) {
+                assert!(pair[0].get().downgrade() <= pair[1].get().downgrade());
+            }
+            let mut expected_unique = expected.clone();
+            expected_unique.dedup();
+
+            // Any input order gives the same sorted and deduplicated output
+            for _ in 0..5 {
+                let mut shuffled = values.clone();
+                shuffle(&mut shuffled, &mut next);
+
+                let mut sorted = shuffled.clone();
+                sorted.sort_unstable();
+                assert_eq!(bits(&sorted), bits(&expected));
+
+                sorted.dedup();
+                assert_eq!(bits(&sorted), bits(&expected_unique));
+
+                let set: BTreeSet<_> = shuffled.iter().copied().collect();
+                assert_eq!(
+                    bits(&set.into_iter().collect::<Vec<_>>()),
+                    bits(&expected_unique)
+                );
+            }
+
+            for value in &values {
+                let index = expected_unique.binary_search(value).unwrap();
+                assert_eq!(expected_unique[index], *value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_eq_and_hash_agree() {
+        let mut next = generator(0x9E37_79B9_7F4A_7C15);
+
+        let values = adversarial_values(&mut next);
+        let mut counts = BTreeMap::new();
+        for value in &values {
+            *counts.entry(*value).or_insert(0) += 1;
+        }
+        for a in &values {
+            for b in &values {
+                if a == b {
+                    assert_eq!(hash_of(a), hash_of(b));
+                }
+            }
+            assert!(counts.contains_key(a));
+        }
+        assert_eq!(counts.values().sum::<i32>(), 300);
+    }
+
+    #[test]
+    fn test_total_order_with_tolerance() {
+        type Loose = TolerantFloat<f64, Relative<3>>;
+
+        // Equal under the policy, still ordered by the exact value
+        let (a, b) = (Loose::new(1.0), Loose::new(1.0001));
+        assert_eq!(a, b);
+        let mut values = [TotalOrd::new(b), TotalOrd::new(a), TotalOrd::new(b)];
+        values.sort();
+        assert_eq!(
+            values.map(|value| value.get().downgrade()),
+            [1.0, 1.0001, 1.0001]
+        );
+        assert_ne!(values[0], values[1]);
+
+        let set: BTreeSet<TotalOrd<f64, Relative<3>>> = values.into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.first().unwrap().get(), a);
+    }
+}
@@ -0,0 +1,69 @@
+/// Implements the parts shared by the comparison wrappers around [`TolerantFloat`]:
+/// `Clone` and `Copy` without bounds on the policy, `new` and `get`, conversions in
+/// both directions, `Debug` showing the raw value and `Display` like the wrapped value.
+///
+/// The wrapper must be a tuple struct holding a `TolerantFloat<T, Tol>`, and the
+/// calling module must import `fmt`, `TolerantFloat`, `DisplayableFloat` and
+/// `Tolerance`.
+///
+/// [`TolerantFloat`]: super::TolerantFloat
+macro_rules! impl_wrapper {
+    ($wrapper:ident, $purpose:literal) => {
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> Clone for $wrapper<T, Tol> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> Copy for $wrapper<T, Tol> {}
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> $wrapper<T, Tol> {
+            #[doc = concat!("Wraps a value for ", $purpose)]
+            #[inline]
+            pub fn new(value: TolerantFloat<T, Tol>) -> Self {
+                Self(value)
+            }
+
+            /// Returns the wrapped value
+            #[inline]
+            pub fn get(&self) -> TolerantFloat<T, Tol> {
+                self.0
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> From<TolerantFloat<T, Tol>>
+            for $wrapper<T, Tol>
+        {
+            #[inline]
+            fn from(value: TolerantFloat<T, Tol>) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> From<$wrapper<T, Tol>>
+            for TolerantFloat<T, Tol>
+        {
+            #[inline]
+            fn from(value: $wrapper<T, Tol>) -> Self {
+                value.0
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> fmt::Debug for $wrapper<T, Tol> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($wrapper))
+                    .field(&self.0.downgrade())
+                    .finish()
+            }
+        }
+
+        impl<T: DisplayableFloat, Tol: Tolerance<T>> fmt::Display for $wrapper<T, Tol> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+pub(super) use impl_wrapper;
@@ -1,2 +1,5 @@
 pub mod extended_float;
 pub use extended_float::ExtendedFloat;
+
+pub mod tick_table;
+pub use tick_table::{TickTable, TickTableError};
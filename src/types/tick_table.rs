@@ -0,0 +1,518 @@
+use std::fmt;
+
+use crate::traits::{DisplayableFloat, Tolerance};
+use crate::types::extended_float::{
+    ConversionError, DefaultTolerance, RoundingMode, StepError, TolerantFloat,
+};
+
+/// Error type for invalid tick table definitions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickTableError {
+    /// No bands were given
+    Empty,
+    /// Band lower bounds are not strictly increasing
+    UnsortedBands,
+    /// Lower bound of a band is NaN or infinite
+    Conversion(ConversionError),
+    /// Tick size of a band is not a positive finite value
+    InvalidTick(StepError),
+}
+
+impl fmt::Display for TickTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TickTableError::Empty => write!(f, "Tick table must have at least one band"),
+            TickTableError::UnsortedBands => {
+                write!(f, "Tick table bands must be sorted by lower bound")
+            }
+            TickTableError::Conversion(error) => fmt::Display::fmt(error, f),
+            TickTableError::InvalidTick(error) => write!(f, "Invalid tick size: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for TickTableError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TickTableError::Conversion(error) => Some(error),
+            TickTableError::InvalidTick(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Price band with a constant tick size
+#[derive(Debug, Clone, Copy)]
+struct Band<T: DisplayableFloat, Tol: Tolerance<T>> {
+    /// Lower bound of the band, inclusive
+    from: TolerantFloat<T, Tol>,
+    tick: TolerantFloat<T, Tol>,
+    /// Fractional digits of valid prices in the band
+    decimals: u32,
+    /// Number of ticks between the lower bound of the first band and this one, an
+    /// integral float so that counts beyond `i64` stay finite
+    offset: T,
+}
+
+/// Tick sizes that depend on the price band, as used by many exchanges.
+///
+/// Each band starts at its lower bound and extends up to the next band, prices below
+/// the first band use its tick size. Valid prices of a band are its lower bound plus
+/// multiples of its tick size. Prices are matched to bands with the tolerant
+/// comparison of the `Tol` policy, [`DefaultTolerance`] unless created with
+/// [`from_bands`](Self::from_bands), so a price within floating-point error of a band
+/// boundary belongs to the upper band.
+///
+/// Tick counts are kept as integral floats, so any finite price works, but counts above
+/// the float mantissa (2^53 for `f64`) are no longer exact. Where the tick is below the
+/// float resolution of the price, rounding returns the nearest representable price and
+/// the next tick up or down may be the price itself.
+///
+/// # Examples
+///
+/// ```
+/// use extended_float::types::extended_float::RoundingMode;
+/// use extended_float::types::{ExtendedFloat, TickTable};
+///
+/// // 0.01 below 10, 0.05 up to 100 and 0.1 above
+/// let table = TickTable::new([(0.0, 0.01), (10.0, 0.05), (100.0, 0.1)]).unwrap();
+///
+/// let price = ExtendedFloat::new(10.03);
+/// assert_eq!(table.tick_at(price), ExtendedFloat::new(0.05));
+/// assert_eq!(table.round_to_tick(price, RoundingMode::HalfUp).format(), "10.05");
+/// assert_eq!(table.round_to_tick(price, RoundingMode::Floor).format(), "10");
+///
+/// assert_eq!(table.next_tick_up(ExtendedFloat::new(9.99)).format(), "10");
+/// assert_eq!(table.next_tick_down(ExtendedFloat::new(10.0)).format(), "9.99");
+/// assert_eq!(table.ticks_between(ExtendedFloat::new(9.9), ExtendedFloat::new(10.1)), 12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TickTable<T: DisplayableFloat, Tol: Tolerance<T> = DefaultTolerance> {
+    bands: Vec<Band<T, Tol>>,
+}
+
+impl<T: DisplayableFloat> TickTable<T> {
+    /// Creates a tick table from `(lower bound, tick size)` pairs sorted by lower bound
+    #[inline]
+    pub fn new<I: IntoIterator<Item = (T, T)>>(bands: I) -> Result<Self, TickTableError> {
+        Self::from_bands(bands)
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> TickTable<T, Tol> {
+    /// Creates a tick table matching prices to bands with the `Tol` policy, from
+    /// `(lower bound, tick size)` pairs sorted by lower bound
+    pub fn from_bands<I: IntoIterator<Item = (T, T)>>(bands: I) -> Result<Self, TickTableError> {
+        let mut table = Self { bands: Vec::new() };
+
+        for (from, tick) in bands {
+            let from = TolerantFloat::try_from_value(from).map_err(TickTableError::Conversion)?;
+            // Validates the tick the same way stepping does
+            TolerantFloat::<T, Tol>::new(T::zero())
+                .steps_by(tick, RoundingMode::Floor)
+                .map_err(TickTableError::InvalidTick)?;
+            let tick = TolerantFloat::new(tick);
+
+            let offset = match table.bands.last() {
+                Some(previous) if from <= previous.from => {
+                    return Err(TickTableError::UnsortedBands);
+                }
+                // Ticks of the previous band up to, but not including, this one
+                Some(previous) => previous.offset + previous.steps_to(from, RoundingMode::Ceiling),
+                None => T::zero(),
+            };
+
+            table.bands.push(Band {
+                from,
+                tick,
+                decimals: fraction_digits(from).max(fraction_digits(tick)),
+                offset,
+            });
+        }
+
+        if table.bands.is_empty() {
+            return Err(TickTableError::Empty);
+        }
+        Ok(table)
+    }
+
+    /// Returns the tick size at `price`
+    #[inline]
+    pub fn tick_at(&self, price: TolerantFloat<T, Tol>) -> TolerantFloat<T, Tol> {
+        self.bands[self.band_index(price)].tick
+    }
+
+    /// Rounds `price` to a valid price according to `mode`.
+    ///
+    /// Rounding up never skips past the lower bound of the next band.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the result is not finite.
+    pub fn round_to_tick(
+        &self,
+        price: TolerantFloat<T, Tol>,
+        mode: RoundingMode,
+    ) -> TolerantFloat<T, Tol> {
+        let index = self.band_index(price);
+        let band = &self.bands[index];
+        let rounded = band.price_at(band.steps_to(price, mode));
+        self.clamp_to_band(index, rounded)
+    }
+
+    /// Returns the smallest valid price above `price`
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the result is not finite.
+    pub fn next_tick_up(&self, price: TolerantFloat<T, Tol>) -> TolerantFloat<T, Tol> {
+        let index = self.band_index(price);
+        let band = &self.bands[index];
+        let next = band.price_at(band.steps_to(price, RoundingMode::Floor) + T::one());
+        self.clamp_to_band(index, next)
+    }
+
+    /// Returns the largest valid price below `price`.
+    ///
+    /// At the lower bound of a band the tick size of the band below applies.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the result is not finite.
+    pub fn next_tick_down(&self, price: TolerantFloat<T, Tol>) -> TolerantFloat<T, Tol> {
+        let mut index = self.band_index(price);
+        if index > 0 && price == self.bands[index].from {
+            index -= 1;
+        }
+        let band = &self.bands[index];
+        band.price_at(band.steps_to(price, RoundingMode::Ceiling) - T::one())
+    }
+
+    /// Returns the number of ticks from `from` to `to`, negative if `to` is below `from`.
+    ///
+    /// Prices between ticks count as the tick below them.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the number of ticks doesn't fit into `i64`.
+    pub fn ticks_between(&self, from: TolerantFloat<T, Tol>, to: TolerantFloat<T, Tol>) -> i64 {
+        (self.tick_index(to) - self.tick_index(from))
+            .to_i64()
+            .expect("Number of ticks fits into i64")
+    }
+
+    /// Index of the band containing `price`
+    #[inline]
+    fn band_index(&self, price: TolerantFloat<T, Tol>) -> usize {
+        // Tolerant comparison puts prices at a boundary into the upper band
+        self.bands
+            .partition_point(|band| band.from <= price)
+            .saturating_sub(1)
+    }
+
+    /// Number of ticks from the lower bound of the first band to the tick at or below `price`
+    fn tick_index(&self, price: TolerantFloat<T, Tol>) -> T {
+        let band = &self.bands[self.band_index(price)];
+        band.offset + band.steps_to(price, RoundingMode::Floor)
+    }
+
+    /// Limits a price computed in band `index` to the lower bound of the next band
+    #[inline]
+    fn clamp_to_band(&self, index: usize, price: TolerantFloat<T, Tol>) -> TolerantFloat<T, Tol> {
+        match self.bands.get(index + 1) {
+            Some(next) if price > next.from => next.from,
+            _ => price,
+        }
+    }
+}
+
+impl<T: DisplayableFloat, Tol: Tolerance<T>> Band<T, Tol> {
+    /// Number of ticks from the lower bound to `price`, rounded according to `mode`
+    fn steps_to(&self, price: TolerantFloat<T, Tol>, mode: RoundingMode) -> T {
+        // The error of the price can be large relative to the tick, so prices
+        // tolerantly equal to a tick are snapped to it before rounding
+        let floor = self.raw_steps_to(price, RoundingMode::Floor);
+        for steps in [floor, floor + T::one()] {
+            if self.price_at(steps) == price {
+                return steps;
+            }
+        }
+        self.raw_steps_to(price, mode)
+    }
+
+    #[inline]
+    fn raw_steps_to(&self, price: TolerantFloat<T, Tol>, mode: RoundingMode) -> T {
+        match (price - self.from).steps_by(self.tick.downgrade(), mode) {
+            Ok(steps) => steps,
+            Err(StepError::Conversion(error)) => error.panic(),
+            Err(error) => unreachable!("Tick size is validated on construction: {}", error),
+        }
+    }
+
+    /// Price `steps` ticks above the lower bound, exactly the float closest to its
+    /// decimal value
+    fn price_at(&self, steps: T) -> TolerantFloat<T, Tol> {
+        let offset = TolerantFloat::from_steps(steps, self.tick.downgrade())
+            .unwrap_or_else(|error| error.panic());
+        (self.from + offset).round_dp(self.decimals, RoundingMode::HalfEven)
+    }
+}
+
+/// Number of fractional digits of a value as it's formatted
+fn fraction_digits<T: DisplayableFloat, Tol: Tolerance<T>>(value: TolerantFloat<T, Tol>) -> u32 {
+    let formatted = value.formatted();
+    formatted
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::types::ExtendedFloat;
+    use crate::types::extended_float::Ulps;
+
+    fn table() -> TickTable<f64> {
+        TickTable::new([(0.0, 0.01), (10.0, 0.05), (100.0, 0.1), (1000.0, 1.0)]).unwrap()
+    }
+
+    fn price(value: f64) -> ExtendedFloat<f64> {
+        ExtendedFloat::new(value)
+    }
+
+    #[test]
+    fn test_tick_at() {
+        let table = table();
+        assert_eq!(table.tick_at(price(0.5)), price(0.01));
+        assert_eq!(table.tick_at(price(9.99)), price(0.01));
+        assert_eq!(table.tick_at(price(10.0)), price(0.05));
+        assert_eq!(table.tick_at(price(99.95)), price(0.05));
+        assert_eq!(table.tick_at(price(100.0)), price(0.1));
+        assert_eq!(table.tick_at(price(5000.0)), price(1.0));
+
+        // Below the first band
+        assert_eq!(table.tick_at(price(-1.0)), price(0.01));
+    }
+
+    #[test]
+    fn test_tick_at_boundary_tolerance() {
+        let table = table();
+
+        // 9.999999999999998, within floating-point error of the boundary
+        let boundary = price(0.1) * price(100.0) - price(2e-15);
+        assert_eq!(table.tick_at(boundary), price(0.05));
+
+        // Accumulated 0.1 steps landing next to 100
+        let mut sum = price(0.0);
+        for _ in 0..1000 {
+            sum += price(0.1);
+        }
+        assert_ne!(sum.downgrade(), 100.0);
+        assert_eq!(table.tick_at(sum), price(0.1));
+        assert_eq!(
+            table.round_to_tick(sum, RoundingMode::Floor).downgrade(),
+            100.0
+        );
+    }
+
+    #[test]
+    fn test_tick_table_tolerance() {
+        type Exact = TolerantFloat<f64, Ulps<0>>;
+
+        let table = TickTable::<f64, Ulps<0>>::from_bands([(0.0, 0.01), (10.0, 0.05)]).unwrap();
+
+        // Just below the boundary is the lower band without the default tolerance
+        let below = Exact::new(10.0).next_down();
+        assert_eq!(table.tick_at(below).downgrade(), 0.01);
+        assert_eq!(table.tick_at(Exact::new(10.0)).downgrade(), 0.05);
+        assert_eq!(table.next_tick_up(below).downgrade(), 10.0);
+        assert_eq!(table.ticks_between(Exact::new(9.9), Exact::new(10.1)), 12);
+    }
+
+    #[test]
+    fn test_round_to_tick() {
+        let table = table();
+        let round = |value, mode| table.round_to_tick(price(value), mode).downgrade();
+
+        assert_eq!(round(1.234, RoundingMode::HalfUp), 1.23);
+        assert_eq!(round(1.235, RoundingMode::HalfUp), 1.24);
+        assert_eq!(round(1.235, RoundingMode::HalfEven), 1.24);
+        assert_eq!(round(1.225, RoundingMode::HalfEven), 1.22);
+        assert_eq!(round(10.03, RoundingMode::HalfUp), 10.05);
+        assert_eq!(round(10.02, RoundingMode::HalfUp), 10.0);
+        assert_eq!(round(10.02, RoundingMode::Ceiling), 10.05);
+        assert_eq!(round(10.07, RoundingMode::Floor), 10.05);
+        assert_eq!(round(99.99, RoundingMode::HalfUp), 100.0);
+        assert_eq!(round(150.04, RoundingMode::HalfUp), 150.0);
+        assert_eq!(round(1234.5, RoundingMode::HalfEven), 1234.0);
+
+        // Valid prices are left alone
+        assert_eq!(round(1.15, RoundingMode::Floor), 1.15);
+        assert_eq!(round(1.15, RoundingMode::Ceiling), 1.15);
+        assert_eq!(round(10.05, RoundingMode::Up), 10.05);
+    }
+
+    #[test]
+    fn test_round_to_tick_unaligned_boundary() {
+        // The next band starts off the grid of the previous one
+        let table = TickTable::new([(0.0, 0.05), (10.03, 0.01)]).unwrap();
+        let round = |value, mode| table.round_to_tick(price(value), mode).downgrade();
+
+        assert_eq!(round(10.01, RoundingMode::Ceiling), 10.03);
+        assert_eq!(round(10.01, RoundingMode::Floor), 10.0);
+        assert_eq!(round(10.04, RoundingMode::Floor), 10.04);
+        assert_eq!(table.next_tick_up(price(10.0)).downgrade(), 10.03);
+        assert_eq!(table.next_tick_down(price(10.03)).downgrade(), 10.0);
+        assert_eq!(table.ticks_between(price(9.95), price(10.04)), 3);
+    }
+
+    #[test]
+    fn test_next_tick() {
+        let table = table();
+        let up = |value| table.next_tick_up(price(value)).downgrade();
+        let down = |value| table.next_tick_down(price(value)).downgrade();
+
+        assert_eq!(up(1.0), 1.01);
+        assert_eq!(up(1.005), 1.01);
+        assert_eq!(up(9.99), 10.0);
+        assert_eq!(up(10.0), 10.05);
+        assert_eq!(up(99.95), 100.0);
+        assert_eq!(up(999.9), 1000.0);
+
+        assert_eq!(down(1.01), 1.0);
+        assert_eq!(down(1.005), 1.0);
+        assert_eq!(down(10.0), 9.99);
+        assert_eq!(down(10.05), 10.0);
+        assert_eq!(down(10.07), 10.05);
+        assert_eq!(down(100.0), 99.95);
+        assert_eq!(down(1000.0), 999.9);
+        assert_eq!(down(0.0), -0.01);
+    }
+
+    #[test]
+    fn test_next_tick_walk() {
+        // Walking up and down must visit the same prices without drift
+        let table = table();
+        let mut prices = vec![price(9.5)];
+        while *prices.last().unwrap() < price(101.0) {
+            prices.push(table.next_tick_up(*prices.last().unwrap()));
+        }
+
+        let mut current = *prices.last().unwrap();
+        for expected in prices.iter().rev().skip(1) {
+            current = table.next_tick_down(current);
+            assert_eq!(current.downgrade(), expected.downgrade());
+            assert_eq!(
+                current.format().parse::<f64>().unwrap(),
+                current.downgrade()
+            );
+        }
+
+        // 50 ticks of 0.01, 1800 of 0.05 and 10 of 0.1
+        assert_eq!(prices.len() - 1, 50 + 1800 + 10);
+        assert_eq!(
+            table.ticks_between(price(9.5), price(101.0)),
+            (prices.len() - 1) as i64
+        );
+    }
+
+    #[test]
+    fn test_ticks_between() {
+        let table = table();
+        assert_eq!(table.ticks_between(price(1.0), price(1.0)), 0);
+        assert_eq!(table.ticks_between(price(1.0), price(2.0)), 100);
+        assert_eq!(table.ticks_between(price(2.0), price(1.0)), -100);
+        assert_eq!(table.ticks_between(price(9.9), price(10.1)), 12);
+        assert_eq!(table.ticks_between(price(0.0), price(10.0)), 1000);
+        assert_eq!(table.ticks_between(price(0.0), price(100.0)), 1000 + 1800);
+
+        // Prices between ticks count as the tick below them
+        assert_eq!(table.ticks_between(price(1.005), price(1.019)), 1);
+
+        // Accumulated errors don't change the count
+        let value = price(0.1) + price(0.2);
+        assert_eq!(table.ticks_between(price(0.0), value), 30);
+    }
+
+    #[test]
+    fn test_huge_prices() {
+        let table = TickTable::new([(0.0, 0.01), (10.0, 0.05)]).unwrap();
+        let huge = price(1e20);
+        assert_eq!(table.round_to_tick(huge, RoundingMode::HalfUp), huge);
+        assert_eq!(table.round_to_tick(huge, RoundingMode::Floor), huge);
+        assert!(table.next_tick_up(huge) >= huge);
+        assert!(table.next_tick_down(huge) <= huge);
+        assert_eq!(table.ticks_between(huge, huge), 0);
+        assert_eq!(table.tick_at(price(-1e300)), price(0.01));
+        assert_eq!(
+            table.round_to_tick(price(-1e300), RoundingMode::Ceiling),
+            price(-1e300)
+        );
+
+        let table = TickTable::new([(0.0, 0.5), (1e15, 4.0)]).unwrap();
+        assert_eq!(
+            table.next_tick_up(price(1e15 + 4.0)).downgrade(),
+            1e15 + 8.0
+        );
+        assert_eq!(table.ticks_between(price(1e15), price(1e15 + 400.0)), 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of ticks fits into i64")]
+    fn test_ticks_between_overflow() {
+        let table = TickTable::new([(0.0, 0.01)]).unwrap();
+        table.ticks_between(price(0.0), price(1e20));
+    }
+
+    #[test]
+    fn test_tick_table_f32() {
+        let table = TickTable::new([(0.0_f32, 0.01), (10.0, 0.05)]).unwrap();
+        let value = ExtendedFloat::new(10.03_f32);
+        assert_eq!(
+            table.round_to_tick(value, RoundingMode::HalfUp).downgrade(),
+            10.05
+        );
+        assert_eq!(
+            table.next_tick_down(ExtendedFloat::new(10.0)).downgrade(),
+            9.99
+        );
+    }
+
+    #[test]
+    fn test_tick_table_errors() {
+        assert_eq!(
+            TickTable::<f64>::new([]).unwrap_err(),
+            TickTableError::Empty
+        );
+        assert_eq!(
+            TickTable::new([(0.0, 0.01), (0.0, 0.05)]).unwrap_err(),
+            TickTableError::UnsortedBands
+        );
+        assert_eq!(
+            TickTable::new([(10.0, 0.01), (1.0, 0.05)]).unwrap_err(),
+            TickTableError::UnsortedBands
+        );
+        assert_eq!(
+            TickTable::new([(0.0, 0.0)]).unwrap_err(),
+            TickTableError::InvalidTick(StepError::ZeroStep)
+        );
+        assert_eq!(
+            TickTable::new([(0.0, -0.01)]).unwrap_err(),
+            TickTableError::InvalidTick(StepError::NegativeStep)
+        );
+        assert_eq!(
+            TickTable::new([(f64::NAN, 0.01)]).unwrap_err(),
+            TickTableError::Conversion(ConversionError::NaN)
+        );
+        assert_eq!(
+            TickTableError::UnsortedBands.to_string(),
+            "Tick table bands must be sorted by lower bound"
+        );
+        assert_eq!(
+            TickTableError::InvalidTick(StepError::ZeroStep).to_string(),
+            "Invalid tick size: Step must not be zero"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! Checks that the allocation-free formatting API doesn't touch the heap.
+//!
+//! Lives in its own test binary, since counting requires a global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+use extended_float::types::ExtendedFloat;
+use extended_float::types::extended_float::FORMAT_BUFFER_LEN;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
+
+#[test]
+fn test_formatting_does_not_allocate() {
+    let values = [
+        ExtendedFloat::new(0.0),
+        ExtendedFloat::new(1e-13),
+        ExtendedFloat::new(0.1) + ExtendedFloat::new(0.2),
+        ExtendedFloat::new(-1234.5678),
+        ExtendedFloat::new(4.00000000000001),
+        ExtendedFloat::new(f64::MAX),
+    ];
+    let mut buffer = [0u8; FORMAT_BUFFER_LEN];
+    let mut output = String::with_capacity(2 * FORMAT_BUFFER_LEN);
+
+    let before = allocations();
+    for value in values {
+        let formatted = value.formatted();
+        let into = value.format_into(&mut buffer).len();
+        output.clear();
+        value.format_to(&mut output).unwrap();
+        assert_eq!(formatted.len(), into);
+        assert_eq!(formatted.len(), output.len());
+        assert!(value.eq_display(&value));
+        assert_eq!(value.cmp_display(&value), Ordering::Equal);
+    }
+    assert_eq!(allocations(), before);
+
+    // Sanity check that allocations are actually counted
+    let _ = values[2].format();
+    assert!(allocations() > before);
+}